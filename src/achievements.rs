@@ -0,0 +1,224 @@
+use std::collections::VecDeque;
+
+use crate::{
+    camera::VIEW_WIDTH,
+    ctx::{Ctx, Layer, Rgb},
+    game::GameResult,
+    inventory::{ItemKind, Requirement, ENTRYWAY_COINS, KITCHEN_COINS, LIVING_ROOM_COINS},
+    locale::Message,
+    profile::Profile,
+    scenes::Scenes,
+    state::State,
+    storage,
+    text::{Align, TextStyle},
+};
+
+const ACHIEVEMENTS_FILE: &str = "achievements.cfg";
+const TOAST_SECONDS: f64 = 3.0;
+const SPEEDRUN_SECONDS: f64 = 120.0;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Achievement {
+    EntrywayCoins,
+    KitchenCoins,
+    LivingRoomCoins,
+    Pacifist,
+    Speedrunner,
+    Completionist,
+    NoLookingBack,
+}
+
+const ALL: [Achievement; 7] = [
+    Achievement::EntrywayCoins,
+    Achievement::KitchenCoins,
+    Achievement::LivingRoomCoins,
+    Achievement::Pacifist,
+    Achievement::Speedrunner,
+    Achievement::Completionist,
+    Achievement::NoLookingBack,
+];
+
+impl Achievement {
+    fn name(&self) -> &'static str {
+        match self {
+            Achievement::EntrywayCoins => "entryway_coins",
+            Achievement::KitchenCoins => "kitchen_coins",
+            Achievement::LivingRoomCoins => "living_room_coins",
+            Achievement::Pacifist => "pacifist",
+            Achievement::Speedrunner => "speedrunner",
+            Achievement::Completionist => "completionist",
+            Achievement::NoLookingBack => "no_looking_back",
+        }
+    }
+
+    fn title(&self) -> Message {
+        match self {
+            Achievement::EntrywayCoins => Message::AchievementEntrywayCoins,
+            Achievement::KitchenCoins => Message::AchievementKitchenCoins,
+            Achievement::LivingRoomCoins => Message::AchievementLivingRoomCoins,
+            Achievement::Pacifist => Message::AchievementPacifist,
+            Achievement::Speedrunner => Message::AchievementSpeedrunner,
+            Achievement::Completionist => Message::AchievementCompletionist,
+            Achievement::NoLookingBack => Message::AchievementNoLookingBack,
+        }
+    }
+}
+
+pub enum Event {
+    PickedUp(ItemKind),
+    SceneEntered(Scenes),
+}
+
+pub struct Achievements {
+    unlocked: Vec<Achievement>,
+    toasts: VecDeque<Achievement>,
+    toast_started: Option<f64>,
+    clean_hands: bool,
+    visited_entryway: bool,
+    left_house: bool,
+    looked_back: bool,
+}
+
+impl Achievements {
+    pub fn load() -> Self {
+        let unlocked = storage::read_pairs(ACHIEVEMENTS_FILE)
+            .into_iter()
+            .filter(|(_, value)| value == "true")
+            .filter_map(|(key, _)| ALL.into_iter().find(|other| other.name() == key))
+            .collect();
+        Self {
+            unlocked,
+            toasts: VecDeque::new(),
+            toast_started: None,
+            clean_hands: true,
+            visited_entryway: false,
+            left_house: false,
+            looked_back: false,
+        }
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let pairs: Vec<_> = ALL
+            .iter()
+            .map(|achievement| {
+                let unlocked = self.unlocked.contains(achievement);
+                (achievement.name(), unlocked.to_string())
+            })
+            .collect();
+        storage::write_pairs(ACHIEVEMENTS_FILE, &pairs)
+    }
+
+    fn unlock(&mut self, achievement: Achievement) {
+        if self.unlocked.contains(&achievement) {
+            return;
+        }
+        self.unlocked.push(achievement);
+        self.toasts.push_back(achievement);
+        if let Err(error) = self.save() {
+            eprintln!("unable to save achievements: {error}");
+        }
+    }
+
+    pub fn start_run(&mut self) {
+        self.clean_hands = true;
+        self.visited_entryway = false;
+        self.left_house = false;
+        self.looked_back = false;
+    }
+
+    pub fn handle(&mut self, event: Event, state: &State) {
+        match event {
+            Event::PickedUp(ItemKind::Coin) => {
+                let rooms = [
+                    (ENTRYWAY_COINS, Achievement::EntrywayCoins),
+                    (KITCHEN_COINS, Achievement::KitchenCoins),
+                    (LIVING_ROOM_COINS, Achievement::LivingRoomCoins),
+                ];
+                for (coins, achievement) in rooms {
+                    if state.meets(&Requirement::AllOf(coins)) {
+                        self.unlock(achievement);
+                    }
+                }
+            }
+            Event::PickedUp(ItemKind::Weapon | ItemKind::Key) => {}
+            Event::SceneEntered(Scenes::Outside) => {
+                self.clean_hands = !state.murder_living_room.dad_dead;
+                self.left_house = self.visited_entryway;
+            }
+            Event::SceneEntered(Scenes::Entryway) => {
+                self.looked_back |= self.left_house;
+                self.visited_entryway = true;
+            }
+            Event::SceneEntered(
+                Scenes::Tutorial
+                | Scenes::LivingRoom
+                | Scenes::MurderLivingRoom
+                | Scenes::Kitchen
+                | Scenes::ChildRoom,
+            ) => {}
+        }
+    }
+
+    pub fn finish_run(&mut self, result: GameResult, seconds: f64, profile: &Profile) {
+        let completed = matches!(result, GameResult::GoodEnding | GameResult::Ascended);
+        if matches!(result, GameResult::GoodEnding) && self.clean_hands {
+            self.unlock(Achievement::Pacifist);
+        }
+        if completed && seconds < SPEEDRUN_SECONDS {
+            self.unlock(Achievement::Speedrunner);
+        }
+        if completed && !self.looked_back {
+            self.unlock(Achievement::NoLookingBack);
+        }
+        if profile.escaped && profile.dead && profile.ascended {
+            self.unlock(Achievement::Completionist);
+        }
+    }
+
+    pub fn draw<C: Ctx>(&mut self, ctx: &mut C) {
+        let Some(achievement) = self.toasts.front() else {
+            return;
+        };
+        let now = ctx.seconds_elapsed();
+        let started = *self.toast_started.get_or_insert(now);
+        let age = now - started;
+        if age >= TOAST_SECONDS {
+            self.toasts.pop_front();
+            self.toast_started = None;
+            return;
+        }
+
+        let language = ctx.language();
+        let text = format!(
+            "{}\n{}",
+            language.text(Message::AchievementUnlocked),
+            language.text(achievement.title())
+        );
+        let style = TextStyle {
+            align: Align::Center,
+            ..TextStyle::small(Rgb(255, 255, 255))
+        };
+        let padding = 0.1875;
+        let (width, height) = style.size(&text);
+        let size = (width + padding * 2.0, height + padding * 2.0);
+        let slide = (age.min(TOAST_SECONDS - age) * 4.0).min(1.0);
+        let position = (
+            (VIEW_WIDTH - size.0) / 2.0,
+            -size.1 + slide * (size.1 + 0.25),
+        );
+        let border = 1.0 / 16.0;
+        ctx.draw_rect(
+            Layer::Overlay,
+            Rgb(255, 255, 255),
+            (position.0 - border, position.1 - border),
+            (size.0 + border * 2.0, size.1 + border * 2.0),
+        );
+        ctx.draw_rect(Layer::Overlay, Rgb(0, 0, 0), position, size);
+        ctx.draw_text(
+            Layer::Overlay,
+            (VIEW_WIDTH / 2.0, position.1 + padding),
+            &text,
+            &style,
+        );
+    }
+}
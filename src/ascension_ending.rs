@@ -0,0 +1,86 @@
+use crate::{
+    achievements::Achievements,
+    camera::Camera,
+    ctx::{Ctx, Key, Layer, Music, Rgb},
+    lifecycle::EndingResult,
+    locale::Message,
+    sprite::Tile,
+    text::{Align, TextStyle},
+    transition::{Transition, TransitionKind},
+};
+
+pub fn ascension_ending<C: Ctx>(
+    ctx: &mut C,
+    achievements: &mut Achievements,
+) -> Result<EndingResult, C::Error> {
+    ctx.set_music(Music::Ascend)?;
+    *ctx.camera_mut() = Camera::new();
+    let transition = Transition::reveal(
+        TransitionKind::Fade(Rgb(255, 255, 255)),
+        ctx.seconds_elapsed(),
+    );
+
+    loop {
+        ctx.setup()?;
+        if ctx.key_down(Key::Quit) {
+            break Ok(EndingResult::Quit);
+        }
+        if ctx.key_down(Key::Interact) && transition.finished(ctx.seconds_elapsed()) {
+            break Ok(EndingResult::Menu);
+        }
+        ctx.draw_background_fill(Rgb(255, 250, 220));
+
+        let beam_offset = ctx.seconds_elapsed() % 4.0;
+        let beam = if beam_offset < 1.0 {
+            Tile::Ascension0
+        } else if beam_offset < 2.0 {
+            Tile::Ascension1
+        } else if beam_offset < 3.0 {
+            Tile::Ascension2
+        } else {
+            Tile::Ascension3
+        };
+        for y in [-2.0, 2.0, 6.0] {
+            ctx.draw_sprite(Layer::Wallpaper, (4.5, y), (1.0, 4.0), &beam);
+        }
+
+        let clouds = [
+            (1.0, 0.0, Tile::Cloud0),
+            (7.0, 3.0, Tile::Cloud1),
+            (3.0, 6.0, Tile::Cloud2),
+            (8.0, 9.0, Tile::Cloud3),
+        ];
+        for (x, y, cloud) in clouds {
+            let y = (y + ctx.seconds_elapsed() * 1.5) % 12.0 - 1.0;
+            ctx.draw_sprite(Layer::Background, (x, y), (1.0, 1.0), &cloud);
+        }
+
+        let angel = if ctx.seconds_elapsed() % 0.2 > 0.1 {
+            Tile::LemonAngel0
+        } else {
+            Tile::LemonAngel1
+        };
+        ctx.draw_sprite(
+            Layer::Actors,
+            (4.5, 5.0 + (ctx.seconds_elapsed() * 2.0).sin() * 0.25),
+            (1.0, 1.0),
+            &angel,
+        );
+
+        let style = TextStyle {
+            pixel_size: 3.0 / 16.0,
+            align: Align::Center,
+            ..TextStyle::small(Rgb(190, 150, 0))
+        };
+        ctx.draw_text(
+            Layer::Ui,
+            (5.0, 1.0 + (ctx.seconds_elapsed() * 1.2).sin() * 0.1),
+            ctx.language().text(Message::AscendedCaption),
+            &style,
+        );
+
+        transition.draw(ctx, (5.0, 5.5));
+        achievements.draw(ctx);
+        ctx.finish()?;
+    }
+}
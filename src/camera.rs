@@ -0,0 +1,52 @@
+pub const VIEW_WIDTH: f64 = 10.0;
+
+const DEADZONE: f64 = 1.5;
+const SMOOTHING: f64 = 4.0;
+
+pub struct Camera {
+    pub x: f64,
+    room_width: f64,
+}
+
+impl Camera {
+    pub fn new() -> Self {
+        Self {
+            x: 0.0,
+            room_width: VIEW_WIDTH,
+        }
+    }
+
+    pub fn room_width(&self) -> f64 {
+        self.room_width
+    }
+
+    pub fn enter_room(&mut self, room_width: f64, target: f64) {
+        self.room_width = room_width;
+        self.x = self.clamp(target + 0.5 - VIEW_WIDTH / 2.0);
+    }
+
+    pub fn follow(&mut self, target: f64, delta_time: f64) {
+        let offset = target + 0.5 - (self.x + VIEW_WIDTH / 2.0);
+        let desired = if offset > DEADZONE {
+            self.x + offset - DEADZONE
+        } else if offset < -DEADZONE {
+            self.x + offset + DEADZONE
+        } else {
+            self.x
+        };
+        let t = (SMOOTHING * delta_time).min(1.0);
+        self.x = self.clamp(self.x + (desired - self.x) * t);
+    }
+
+    pub fn to_view(&self, position: (f64, f64)) -> (f64, f64) {
+        (position.0 - self.x, position.1)
+    }
+
+    pub fn parallax(&self, x: f64, factor: f64) -> f64 {
+        x + self.x * (1.0 - factor)
+    }
+
+    fn clamp(&self, x: f64) -> f64 {
+        x.clamp(0.0, (self.room_width - VIEW_WIDTH).max(0.0))
+    }
+}
@@ -0,0 +1,199 @@
+use std::path::PathBuf;
+
+use crate::{
+    locale::Language,
+    settings::{Renderer, ScaleMode, Settings, WindowMode},
+};
+
+pub const USAGE: &str = "\
+usage: lemonhead [options]
+
+  --scene <name>         start in a room, skipping the menu
+  --state <file>         load state fields from a key=value file
+  --ending <which>       preview an ending: good, dead or ascend
+  --record <file>        record inputs to a file
+  --replay <file>        play back recorded inputs
+  --mute                 disable music and sound effects
+  --assets <dir>         load sprites and audio from another directory
+  --seed <number>        seed the random number generator
+  --timer, --no-timer    toggle the speedrun timer
+  --windowed             run in a window
+  --fullscreen           run in exclusive fullscreen
+  --borderless           run in borderless fullscreen
+  --vsync, --no-vsync    toggle vertical sync
+  --display <index>      open on another display
+  --renderer <kind>      accelerated or software
+  --fps <number>         frame rate cap, 0 for none
+  --scale <mode>         pixel-perfect, letterbox or stretch
+  --language <code>      en, da or ru
+  --help                 show this message";
+
+#[derive(Clone, Copy)]
+pub enum Ending {
+    Good,
+    Dead,
+    Ascend,
+}
+
+pub struct Overrides {
+    pub window_mode: Option<WindowMode>,
+    pub vsync: Option<bool>,
+    pub speedrun_timer: Option<bool>,
+    pub display: Option<i32>,
+    pub renderer: Option<Renderer>,
+    pub fps: Option<u32>,
+    pub scale_mode: Option<ScaleMode>,
+    pub language: Option<Language>,
+}
+
+pub struct Options {
+    pub scene: Option<String>,
+    pub state: Option<PathBuf>,
+    pub ending: Option<Ending>,
+    pub record: Option<PathBuf>,
+    pub replay: Option<PathBuf>,
+    pub mute: bool,
+    pub assets: PathBuf,
+    pub seed: Option<u64>,
+    pub help: bool,
+    pub overrides: Overrides,
+}
+
+impl Overrides {
+    pub fn apply(&self, settings: &Settings) -> Settings {
+        let mut settings = settings.clone();
+        if let Some(window_mode) = self.window_mode {
+            settings.window_mode = window_mode;
+        }
+        if let Some(vsync) = self.vsync {
+            settings.vsync = vsync;
+        }
+        if let Some(speedrun_timer) = self.speedrun_timer {
+            settings.speedrun_timer = speedrun_timer;
+        }
+        if let Some(display) = self.display {
+            settings.display = display;
+            settings.window_position = None;
+        }
+        if let Some(renderer) = self.renderer {
+            settings.renderer = renderer;
+        }
+        if let Some(fps) = self.fps {
+            settings.fps = fps;
+        }
+        if let Some(scale_mode) = self.scale_mode {
+            settings.scale_mode = scale_mode;
+        }
+        if let Some(language) = self.language {
+            settings.language = language;
+        }
+        settings
+    }
+}
+
+impl Options {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            scene: None,
+            state: None,
+            ending: None,
+            record: None,
+            replay: None,
+            mute: false,
+            assets: PathBuf::from("assets"),
+            seed: None,
+            help: false,
+            overrides: Overrides {
+                window_mode: None,
+                vsync: None,
+                speedrun_timer: None,
+                display: None,
+                renderer: None,
+                fps: None,
+                scale_mode: None,
+                language: None,
+            },
+        };
+        let overrides = &mut options.overrides;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--scene" => options.scene = Some(args.next().ok_or("--scene expects a scene")?),
+                "--state" => {
+                    options.state = Some(args.next().ok_or("--state expects a file")?.into());
+                }
+                "--ending" => {
+                    let ending = args.next().ok_or("--ending expects an ending")?;
+                    options.ending = Some(match ending.as_str() {
+                        "good" => Ending::Good,
+                        "dead" => Ending::Dead,
+                        "ascend" => Ending::Ascend,
+                        _ => return Err(format!("unknown ending '{ending}'")),
+                    });
+                }
+                "--record" => {
+                    options.record = Some(args.next().ok_or("--record expects a file")?.into());
+                }
+                "--replay" => {
+                    options.replay = Some(args.next().ok_or("--replay expects a file")?.into());
+                }
+                "--mute" => options.mute = true,
+                "--assets" => {
+                    options.assets = args.next().ok_or("--assets expects a directory")?.into();
+                }
+                "--seed" => {
+                    let seed = args.next().ok_or("--seed expects a number")?;
+                    options.seed =
+                        Some(seed.parse().map_err(|_| format!("invalid seed '{seed}'"))?);
+                }
+                "--help" | "-h" => options.help = true,
+                "--windowed" => overrides.window_mode = Some(WindowMode::Windowed),
+                "--fullscreen" => overrides.window_mode = Some(WindowMode::Fullscreen),
+                "--borderless" => overrides.window_mode = Some(WindowMode::Borderless),
+                "--vsync" => overrides.vsync = Some(true),
+                "--no-vsync" => overrides.vsync = Some(false),
+                "--timer" => overrides.speedrun_timer = Some(true),
+                "--no-timer" => overrides.speedrun_timer = Some(false),
+                "--display" => {
+                    let display = args.next().ok_or("--display expects an index")?;
+                    overrides.display = Some(
+                        display
+                            .parse()
+                            .map_err(|_| format!("invalid display index '{display}'"))?,
+                    );
+                }
+                "--renderer" => {
+                    let renderer = args.next().ok_or("--renderer expects a renderer")?;
+                    overrides.renderer = Some(
+                        Renderer::parse(&renderer)
+                            .ok_or(format!("unknown renderer '{renderer}'"))?,
+                    );
+                }
+                "--fps" => {
+                    let fps = args.next().ok_or("--fps expects a frame rate")?;
+                    overrides.fps = Some(
+                        fps.parse()
+                            .map_err(|_| format!("invalid frame rate '{fps}'"))?,
+                    );
+                }
+                "--scale" => {
+                    let mode = args.next().ok_or("--scale expects a mode")?;
+                    overrides.scale_mode = Some(
+                        ScaleMode::parse(&mode).ok_or(format!("unknown scale mode '{mode}'"))?,
+                    );
+                }
+                "--language" => {
+                    let language = args.next().ok_or("--language expects a language")?;
+                    overrides.language = Some(
+                        Language::parse(&language)
+                            .ok_or(format!("unknown language '{language}'"))?,
+                    );
+                }
+                _ => return Err(format!("unknown argument '{arg}', see --help")),
+            }
+        }
+        if options.record.is_some() && options.replay.is_some() {
+            return Err("--record and --replay cannot be combined".to_owned());
+        }
+        Ok(options)
+    }
+}
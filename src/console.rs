@@ -0,0 +1,142 @@
+use crate::{
+    camera::VIEW_WIDTH,
+    ctx::{Ctx, Key, Layer, Music, Rgb},
+    game::GameResult,
+    scenes::Scenes,
+    text::{Spacing, TextStyle},
+};
+
+const HISTORY_LINES: usize = 6;
+
+pub enum Command {
+    Warp(Scenes, f64),
+    Set(String, String),
+    Music(Option<Music>),
+    Ending(GameResult),
+    Ascend,
+    TimeScale(f64),
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Self, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            ["warp", scene] | ["warp", scene, _] => {
+                let x = match words.get(2) {
+                    Some(x) => x.parse().map_err(|_| format!("invalid position '{x}'"))?,
+                    None => 1.0,
+                };
+                let scene = Scenes::parse(scene).ok_or(format!("unknown scene '{scene}'"))?;
+                Ok(Command::Warp(scene, x))
+            }
+            ["set", field, value] => Ok(Command::Set(field.to_string(), value.to_string())),
+            ["clear", field] => {
+                let value = if field.ends_with("stabs") || field.ends_with("seconds") {
+                    "0"
+                } else {
+                    "false"
+                };
+                Ok(Command::Set(field.to_string(), value.to_owned()))
+            }
+            ["music", "off"] => Ok(Command::Music(None)),
+            ["music", name] => Music::parse(name)
+                .map(|music| Command::Music(Some(music)))
+                .ok_or(format!("unknown music '{name}'")),
+            ["ending", "good"] => Ok(Command::Ending(GameResult::GoodEnding)),
+            ["ending", "dead"] => Ok(Command::Ending(GameResult::Dead)),
+            ["ending", "ascend"] => Ok(Command::Ascend),
+            ["timescale", scale] => scale
+                .parse()
+                .ok()
+                .filter(|scale: &f64| *scale > 0.0)
+                .map(Command::TimeScale)
+                .ok_or(format!("invalid time scale '{scale}'")),
+            [] => Err(String::new()),
+            [command, ..] => Err(format!("unknown command '{command}'")),
+        }
+    }
+}
+
+pub struct Console {
+    open: bool,
+    input: String,
+    history: Vec<String>,
+}
+
+impl Console {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            input: String::new(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn open(&self) -> bool {
+        self.open
+    }
+
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.history.push(line.into());
+    }
+
+    pub fn update<C: Ctx>(&mut self, ctx: &C) -> Option<Command> {
+        if ctx.key_down(Key::Console) {
+            self.open = !self.open;
+            self.input.clear();
+            return None;
+        }
+        if !self.open {
+            return None;
+        }
+        self.input.push_str(ctx.text_input());
+        if ctx.key_down(Key::Backspace) {
+            self.input.pop();
+        }
+        if !ctx.key_down(Key::Submit) {
+            return None;
+        }
+        let line = std::mem::take(&mut self.input);
+        self.print(format!("> {line}"));
+        match Command::parse(&line) {
+            Ok(command) => Some(command),
+            Err(error) => {
+                if !error.is_empty() {
+                    self.print(error);
+                }
+                None
+            }
+        }
+    }
+
+    pub fn draw<C: Ctx>(&self, ctx: &mut C) {
+        if !self.open {
+            return;
+        }
+        let style = TextStyle {
+            spacing: Spacing::Monospace,
+            ..TextStyle::small(Rgb(255, 255, 255))
+        };
+        let padding = 0.125;
+        let skip = self.history.len().saturating_sub(HISTORY_LINES);
+        let mut lines: Vec<&str> = self.history[skip..].iter().map(String::as_str).collect();
+        let prompt = format!("] {}_", self.input);
+        lines.push(&prompt);
+
+        let height = (lines.len() as f64 - 1.0) * style.line_height() + style.cap_height();
+        let top = VIEW_WIDTH - height - padding * 2.0;
+        ctx.draw_rect_alpha(
+            Layer::Overlay,
+            Rgb(0, 0, 0),
+            200,
+            (0.0, top),
+            (VIEW_WIDTH, height + padding * 2.0),
+        );
+        ctx.draw_text(
+            Layer::Overlay,
+            (padding, top + padding),
+            &lines.join("\n"),
+            &style,
+        );
+    }
+}
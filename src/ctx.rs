@@ -1,13 +1,42 @@
-use crate::sprite::Sprite;
+use crate::{
+    camera::{Camera, VIEW_WIDTH},
+    locale::Language,
+    sprite::{Glyph, Sprite},
+    text::{Align, TextStyle, GLYPH_CELL},
+};
 
 #[derive(Clone, Copy)]
 pub struct Rgb(pub u8, pub u8, pub u8);
 
-#[derive(Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Layer {
+    Background,
+    Wallpaper,
+    Props,
+    Decals,
+    Actors,
+    Ui,
+    Overlay,
+}
+
+impl Layer {
+    pub fn scrolls(&self) -> bool {
+        !matches!(self, Layer::Ui | Layer::Overlay)
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Key {
     Left,
     Right,
     Interact,
+    Jump,
+    Debug,
+    Hud,
+    Inventory,
+    Console,
+    Backspace,
+    Submit,
     Quit,
 }
 
@@ -24,7 +53,7 @@ pub enum Effect {
     Stab,
 }
 
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 pub enum Music {
     Outside,
     Lemonhead,
@@ -64,6 +93,36 @@ impl Music {
         }
     }
 
+    pub fn name(&self) -> &'static str {
+        match self {
+            Music::Outside => "outside",
+            Music::Lemonhead => "lemonhead",
+            Music::RipBozo => "ripbozo",
+            Music::Rich => "rich",
+            Music::Run => "run",
+            Music::Heartbeat => "heartbeat",
+            Music::HeartbeatChild => "heartbeat_child",
+            Music::HeartbeatChildWithLemon => "heartbeat_child_with_lemon",
+            Music::Ascend => "ascend",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            Music::Outside,
+            Music::Lemonhead,
+            Music::RipBozo,
+            Music::Rich,
+            Music::Run,
+            Music::Heartbeat,
+            Music::HeartbeatChild,
+            Music::HeartbeatChildWithLemon,
+            Music::Ascend,
+        ]
+        .into_iter()
+        .find(|music| music.name() == value)
+    }
+
     pub fn volume(&self) -> f32 {
         match self {
             Music::Lemonhead | Music::Ascend => 1.0,
@@ -77,30 +136,74 @@ pub trait Ctx {
     type Error;
 
     fn draw_background_fill(&mut self, color: Rgb);
-    fn draw_sprite(&mut self, position: (f64, f64), size: (f64, f64), sprite: &impl Sprite);
-    fn draw_border(&mut self) {
-        let border_color = Rgb(50, 50, 50);
-        let (left, top) = self.to_screen_position((0.0, 0.0));
-        let (right, bottom) = self.to_screen_position((10.0, 10.0));
-        let (win_width, win_height) = self.window_size();
-        self.draw_screen_rect(border_color, (0.0, 0.0), (left, win_height));
-        self.draw_screen_rect(border_color, (0.0, 0.0), (win_width, top));
-        self.draw_screen_rect(border_color, (0.0, bottom), (win_width, top));
-        self.draw_screen_rect(border_color, (right, 0.0), (left, win_height));
+    fn draw_sprite(
+        &mut self,
+        layer: Layer,
+        position: (f64, f64),
+        size: (f64, f64),
+        sprite: &impl Sprite,
+    ) {
+        self.draw_sprite_tinted(layer, position, size, sprite, Rgb(255, 255, 255));
+    }
+    fn draw_sprite_tinted(
+        &mut self,
+        layer: Layer,
+        position: (f64, f64),
+        size: (f64, f64),
+        sprite: &impl Sprite,
+        tint: Rgb,
+    ) {
+        self.draw_sprite_alpha(layer, position, size, sprite, tint, 255);
+    }
+    fn draw_sprite_alpha(
+        &mut self,
+        layer: Layer,
+        position: (f64, f64),
+        size: (f64, f64),
+        sprite: &impl Sprite,
+        tint: Rgb,
+        alpha: u8,
+    );
+    fn draw_rect(&mut self, layer: Layer, color: Rgb, position: (f64, f64), size: (f64, f64)) {
+        self.draw_rect_alpha(layer, color, 255, position, size);
+    }
+    fn draw_rect_alpha(
+        &mut self,
+        layer: Layer,
+        color: Rgb,
+        alpha: u8,
+        position: (f64, f64),
+        size: (f64, f64),
+    );
+    fn draw_text(&mut self, layer: Layer, position: (f64, f64), text: &str, style: &TextStyle) {
+        let cell = GLYPH_CELL * style.pixel_size;
+        for (index, line) in style.lines(text).iter().enumerate() {
+            let width = style.measure(line);
+            let mut x = match style.align {
+                Align::Left => position.0,
+                Align::Center => position.0 - width / 2.0,
+                Align::Right => position.0 - width,
+            };
+            let y = position.1 + index as f64 * style.line_height();
+            for character in line.chars() {
+                let glyph = Glyph::new(character);
+                if character != ' ' {
+                    self.draw_sprite_tinted(layer, (x, y), (cell, cell), &glyph, style.color);
+                }
+                x += style.advance(&glyph);
+            }
+        }
     }
-    fn draw_screen_rect(&mut self, color: Rgb, position: (f64, f64), size: (f64, f64));
     fn to_screen_scale(&self, size: (f64, f64)) -> (f64, f64) {
-        let (win_x, win_y) = self.window_size();
+        let (win_x, win_y) = self.render_size();
         let win_min = f64_min(win_x, win_y);
-        let win_min = win_min - win_min % 10.0;
-        let pixels_per_dot = win_min / 10.0;
+        let pixels_per_dot = win_min / VIEW_WIDTH;
         (size.0 * pixels_per_dot, size.1 * pixels_per_dot)
     }
     fn to_screen_position(&self, position: (f64, f64)) -> (f64, f64) {
-        let (win_x, win_y) = self.window_size();
+        let (win_x, win_y) = self.render_size();
         let win_min = f64_min(win_x, win_y);
-        let win_min = win_min - win_min % 10.0;
-        let pixels_per_dot = win_min / 10.0;
+        let pixels_per_dot = win_min / VIEW_WIDTH;
         let center_x = (win_x - win_min) / 2.0;
         let center_y = (win_y - win_min) / 2.0;
         (
@@ -108,14 +211,29 @@ pub trait Ctx {
             center_y + position.1 * pixels_per_dot,
         )
     }
-    fn window_size(&self) -> (f64, f64);
+    fn to_view_position(&self, layer: Layer, position: (f64, f64)) -> (f64, f64) {
+        if layer.scrolls() {
+            self.camera().to_view(position)
+        } else {
+            position
+        }
+    }
+    fn render_size(&self) -> (f64, f64);
+    fn camera(&self) -> &Camera;
+    fn camera_mut(&mut self) -> &mut Camera;
+    fn language(&self) -> Language;
 
     fn play_effect(&mut self, effect: Effect) -> Result<(), Self::Error>;
     fn set_music(&mut self, music: Music) -> Result<(), Self::Error>;
     fn stop_music(&mut self) -> Result<(), Self::Error>;
     fn key_down(&self, key: Key) -> bool;
+    fn text_input(&self) -> &str;
+    fn playing_music(&self) -> Option<Music>;
+    fn render_queue_len(&self) -> usize;
 
     fn setup(&mut self) -> Result<(), Self::Error>;
     fn finish(&mut self) -> Result<(), Self::Error>;
     fn seconds_elapsed(&self) -> f64;
+    fn set_time_scale(&mut self, scale: f64);
+    fn random(&mut self) -> f64;
 }
@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+
+use crate::{
+    ctx::{Ctx, Key, Music},
+    game::{GameResult, Lemonhead},
+    globals::GROUND_LEVEL,
+    helper::CtxHelperExt,
+    locale::Message,
+    scenes::Scenes,
+    sprite::ActorState,
+    state::State,
+};
+
+#[derive(Clone, Copy)]
+pub enum Actor {
+    Lemonhead,
+    Dad,
+}
+
+pub enum Action {
+    Move {
+        actor: Actor,
+        to: (f64, f64),
+        seconds: f64,
+    },
+    PlayMusic(Music),
+    ShowText {
+        text: Message,
+        seconds: f64,
+    },
+    WaitForInput,
+    Wait(f64),
+    ChangeScene(f64, Scenes),
+    End(GameResult),
+}
+
+pub struct Cutscene {
+    actions: VecDeque<Action>,
+    blocking: bool,
+    elapsed: f64,
+    from: Option<(f64, f64)>,
+}
+
+impl Cutscene {
+    pub fn new(actions: impl IntoIterator<Item = Action>) -> Self {
+        Self {
+            actions: actions.into_iter().collect(),
+            blocking: true,
+            elapsed: 0.0,
+            from: None,
+        }
+    }
+
+    pub fn dad_approach(from: f64) -> Self {
+        Self {
+            blocking: false,
+            ..Self::new([Action::Move {
+                actor: Actor::Dad,
+                to: (-1.0, GROUND_LEVEL),
+                seconds: ((from + 1.0) / 2.0).max(0.0),
+            }])
+        }
+    }
+
+    pub fn ride_away() -> Self {
+        Self::new([
+            Action::Move {
+                actor: Actor::Lemonhead,
+                to: (8.0, GROUND_LEVEL),
+                seconds: 0.25,
+            },
+            Action::Wait(0.25),
+            Action::ChangeScene(1.0, Scenes::Outside),
+        ])
+    }
+
+    pub fn escape() -> Self {
+        Self::new([
+            Action::ShowText {
+                text: Message::CutsceneEscape,
+                seconds: 1.5,
+            },
+            Action::Move {
+                actor: Actor::Lemonhead,
+                to: (-1.0, GROUND_LEVEL),
+                seconds: 4.0,
+            },
+            Action::End(GameResult::GoodEnding),
+        ])
+    }
+
+    pub fn ascension() -> Self {
+        Self::new([
+            Action::PlayMusic(Music::Ascend),
+            Action::Move {
+                actor: Actor::Lemonhead,
+                to: (3.0, -1.0),
+                seconds: 24.0,
+            },
+            Action::WaitForInput,
+            Action::End(GameResult::Ascended),
+        ])
+    }
+
+    pub fn finished(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    pub fn blocking(&self) -> bool {
+        self.blocking
+    }
+
+    pub fn update<C: Ctx>(
+        &mut self,
+        ctx: &mut C,
+        state: &mut State,
+        lemonhead: &mut Lemonhead,
+        delta_time: f64,
+    ) -> Result<Option<GameResult>, C::Error> {
+        self.elapsed += delta_time;
+        while let Some(action) = self.actions.pop_front() {
+            let elapsed = self.elapsed;
+            let done = match &action {
+                Action::Move { actor, to, seconds } => {
+                    let from = *self.from.get_or_insert(match actor {
+                        Actor::Lemonhead => (lemonhead.x, lemonhead.y),
+                        Actor::Dad => (state.living_room.dad_position, GROUND_LEVEL),
+                    });
+                    let t = (elapsed / seconds).min(1.0);
+                    let x = from.0 + (to.0 - from.0) * t;
+                    let y = from.1 + (to.1 - from.1) * t;
+                    match actor {
+                        Actor::Lemonhead => {
+                            lemonhead.x = x;
+                            lemonhead.y = y;
+                            if to.0 < from.0 {
+                                lemonhead.state = ActorState::Left;
+                            } else if to.0 > from.0 {
+                                lemonhead.state = ActorState::Right;
+                            }
+                        }
+                        Actor::Dad => state.living_room.dad_position = x,
+                    }
+                    t >= 1.0
+                }
+                Action::PlayMusic(music) => {
+                    ctx.set_music(*music)?;
+                    true
+                }
+                Action::ShowText { text, seconds } => {
+                    ctx.draw_caption(ctx.language().text(*text), 1.5);
+                    elapsed >= *seconds
+                }
+                Action::WaitForInput => ctx.key_down(Key::Interact),
+                Action::Wait(seconds) => elapsed >= *seconds,
+                Action::ChangeScene(..) | Action::End(_) => true,
+            };
+            if !done {
+                self.actions.push_front(action);
+                break;
+            }
+            self.elapsed = 0.0;
+            self.from = None;
+            match action {
+                Action::ChangeScene(position, scene) => {
+                    state.scene_changed = Some((position, scene));
+                }
+                Action::End(result) => return Ok(Some(result)),
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+}
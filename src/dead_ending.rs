@@ -1,40 +1,111 @@
 use crate::{
-    ctx::{Ctx, Key, Music, Rgb},
+    achievements::Achievements,
+    camera::Camera,
+    ctx::{Ctx, Key, Layer, Music, Rgb},
     globals::GROUND_LEVEL,
+    lifecycle::EndingResult,
+    locale::Message,
     sprite::Tile,
+    text::{Align, TextStyle},
+    transition::{Transition, TransitionKind},
 };
 
-pub fn dead_ending<C: Ctx>(ctx: &mut C) -> Result<(), C::Error> {
+pub fn dead_ending<C: Ctx>(
+    ctx: &mut C,
+    achievements: &mut Achievements,
+) -> Result<EndingResult, C::Error> {
     ctx.set_music(Music::RipBozo)?;
+    *ctx.camera_mut() = Camera::new();
+    let transition = Transition::reveal(TransitionKind::Fade(Rgb(0, 0, 0)), ctx.seconds_elapsed());
+    let mut retry = true;
+    let drift: [f64; 4] = std::array::from_fn(|_| 0.8 + ctx.random() * 0.4);
 
     loop {
         ctx.setup()?;
         ctx.draw_background_fill(Rgb(54, 54, 54));
-        if ctx.key_down(Key::Quit) || ctx.key_down(Key::Interact) {
-            break Ok(());
+        if ctx.key_down(Key::Quit) {
+            break Ok(EndingResult::Quit);
+        }
+        if ctx.key_down(Key::Left) {
+            retry = true;
+        } else if ctx.key_down(Key::Right) {
+            retry = false;
+        }
+        if ctx.key_down(Key::Interact) && transition.finished(ctx.seconds_elapsed()) {
+            break Ok(if retry {
+                EndingResult::Retry
+            } else {
+                EndingResult::Menu
+            });
         }
 
         let offset = ctx.seconds_elapsed() % 5.0 * 4.0;
 
-        ctx.draw_sprite((-3.0 + offset * 1.1, 1.0), (1.0, 1.0), &Tile::Cloud0);
-        ctx.draw_sprite((-6.0 + offset, 2.0), (1.0, 1.0), &Tile::Cloud1);
-        ctx.draw_sprite((-8.0 + offset * 0.9, 1.0), (1.0, 1.0), &Tile::Cloud2);
-        ctx.draw_sprite((-1.0 + offset * 1.2, 2.0), (1.0, 1.0), &Tile::Cloud3);
-        ctx.draw_sprite((5.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Cross);
+        ctx.draw_sprite(
+            Layer::Wallpaper,
+            (-3.0 + offset * drift[0], 1.0),
+            (1.0, 1.0),
+            &Tile::Cloud0,
+        );
+        ctx.draw_sprite(
+            Layer::Wallpaper,
+            (-6.0 + offset * drift[1], 2.0),
+            (1.0, 1.0),
+            &Tile::Cloud1,
+        );
+        ctx.draw_sprite(
+            Layer::Wallpaper,
+            (-8.0 + offset * drift[2], 1.0),
+            (1.0, 1.0),
+            &Tile::Cloud2,
+        );
+        ctx.draw_sprite(
+            Layer::Wallpaper,
+            (-1.0 + offset * drift[3], 2.0),
+            (1.0, 1.0),
+            &Tile::Cloud3,
+        );
+        ctx.draw_sprite(Layer::Props, (5.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Cross);
 
-        ctx.draw_sprite((2.0, GROUND_LEVEL), (1.0, 1.0), &Tile::TreeTrunk);
-        ctx.draw_sprite((2.0, GROUND_LEVEL - 1.0), (1.0, 1.0), &Tile::TreeLeaves);
+        ctx.draw_sprite(
+            Layer::Props,
+            (2.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::TreeTrunk,
+        );
+        ctx.draw_sprite(
+            Layer::Props,
+            (2.0, GROUND_LEVEL - 1.0),
+            (1.0, 1.0),
+            &Tile::TreeLeaves,
+        );
         for i in 0..10 {
-            ctx.draw_sprite((f64::from(i), GROUND_LEVEL), (1.0, 1.0), &Tile::Grass);
+            ctx.draw_sprite(
+                Layer::Wallpaper,
+                (f64::from(i), GROUND_LEVEL),
+                (1.0, 1.0),
+                &Tile::Grass,
+            );
         }
-        ctx.draw_sprite((0.0, GROUND_LEVEL + 1.0), (10.0, 1.0), &Tile::Ground);
         ctx.draw_sprite(
+            Layer::Background,
+            (0.0, GROUND_LEVEL + 1.0),
+            (10.0, 1.0),
+            &Tile::Ground,
+        );
+        ctx.draw_sprite(
+            Layer::Background,
             (0.0, GROUND_LEVEL + 2.0),
             (10.0, 10.0 - GROUND_LEVEL - 2.0),
             &Tile::Block,
         );
 
-        ctx.draw_sprite((5.0, GROUND_LEVEL + 2.0), (1.0, 0.0), &Tile::LemonSkull);
+        ctx.draw_sprite(
+            Layer::Decals,
+            (5.0, GROUND_LEVEL + 2.0),
+            (1.0, 0.0),
+            &Tile::LemonSkull,
+        );
 
         let angel = if ctx.seconds_elapsed() % 0.2 > 0.1 {
             Tile::LemonAngel0
@@ -43,6 +114,7 @@ pub fn dead_ending<C: Ctx>(ctx: &mut C) -> Result<(), C::Error> {
         };
 
         ctx.draw_sprite(
+            Layer::Actors,
             (
                 4.5,
                 GROUND_LEVEL - (1.0 - (ctx.seconds_elapsed() * 4.0).sin() * 0.15),
@@ -51,12 +123,40 @@ pub fn dead_ending<C: Ctx>(ctx: &mut C) -> Result<(), C::Error> {
             &angel,
         );
 
-        ctx.draw_sprite(
-            (2.0, 1.0 + (ctx.seconds_elapsed() * 1.2).sin() * 0.1),
-            (6.0, 3.0),
-            &Tile::GameOver,
+        let style = TextStyle {
+            pixel_size: 3.0 / 16.0,
+            align: Align::Center,
+            ..TextStyle::small(Rgb(170, 170, 170))
+        };
+        ctx.draw_text(
+            Layer::Ui,
+            (5.0, 1.0 + (ctx.seconds_elapsed() * 1.2).sin() * 0.1),
+            ctx.language().text(Message::DeadCaption),
+            &style,
         );
+        let choices = [
+            (Message::DeadRetry, 3.0, retry),
+            (Message::DeadMenu, 7.0, !retry),
+        ];
+        for (message, x, selected) in choices {
+            let color = if selected {
+                Rgb(255, 255, 255)
+            } else {
+                Rgb(110, 110, 110)
+            };
+            ctx.draw_text(
+                Layer::Ui,
+                (x, 4.0),
+                ctx.language().text(message),
+                &TextStyle {
+                    align: Align::Center,
+                    ..TextStyle::large(color)
+                },
+            );
+        }
 
+        transition.draw(ctx, (5.0, GROUND_LEVEL));
+        achievements.draw(ctx);
         ctx.finish()?;
     }
 }
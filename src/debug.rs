@@ -0,0 +1,113 @@
+use crate::{
+    ctx::{Ctx, Key, Layer, Rgb},
+    game::Lemonhead,
+    globals::GROUND_LEVEL,
+    scenes::{Scenes, INTERACT_DISTANCE},
+    state::State,
+    text::{Spacing, TextStyle},
+};
+
+const SMOOTHING: f64 = 0.1;
+
+pub struct DebugOverlay {
+    enabled: bool,
+    frame_time: f64,
+}
+
+impl DebugOverlay {
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            frame_time: 0.0,
+        }
+    }
+
+    pub fn update<C: Ctx>(&mut self, ctx: &C, delta_time: f64) {
+        if ctx.key_down(Key::Debug) {
+            self.enabled = !self.enabled;
+        }
+        self.frame_time += (delta_time - self.frame_time) * SMOOTHING;
+    }
+
+    pub fn draw<C: Ctx>(&self, ctx: &mut C, scene: &Scenes, state: &State, lemonhead: &Lemonhead) {
+        if !self.enabled {
+            return;
+        }
+
+        for collider in scene.colliders(state) {
+            let (left, top) = ctx
+                .camera()
+                .to_view((collider.x, GROUND_LEVEL + 1.0 - collider.height));
+            let color = if collider.solid {
+                Rgb(255, 0, 0)
+            } else {
+                Rgb(255, 255, 0)
+            };
+            ctx.draw_rect_alpha(
+                Layer::Overlay,
+                color,
+                64,
+                (left, top),
+                (collider.width, collider.height),
+            );
+        }
+        for (x, height) in scene.item_positions(state) {
+            let (left, top) = ctx
+                .camera()
+                .to_view((x + 0.5 - INTERACT_DISTANCE, GROUND_LEVEL - height));
+            ctx.draw_rect_alpha(
+                Layer::Overlay,
+                Rgb(0, 255, 0),
+                64,
+                (left, top),
+                (INTERACT_DISTANCE * 2.0, 1.0),
+            );
+            ctx.draw_rect(
+                Layer::Overlay,
+                Rgb(0, 255, 0),
+                (left + INTERACT_DISTANCE - 0.03125, top),
+                (0.0625, 1.0),
+            );
+        }
+        let (lemon_x, lemon_y) = ctx.camera().to_view((lemonhead.x + 0.5, lemonhead.y));
+        ctx.draw_rect(
+            Layer::Overlay,
+            Rgb(255, 0, 255),
+            (lemon_x - 0.03125, lemon_y),
+            (0.0625, 1.0),
+        );
+
+        let mut lines = vec![
+            format!("SCENE {}", scene.name()),
+            format!("LEMON {:.2} {:.2}", lemonhead.x, lemonhead.y),
+        ];
+        if matches!(scene, Scenes::LivingRoom) && state.dad_chasing() {
+            lines.push(format!("DAD {:.2}", state.living_room.dad_position));
+        }
+        let music = ctx.playing_music().map_or("none", |music| music.name());
+        lines.push(format!("MUSIC {music}"));
+        let fps = if self.frame_time > 0.0 {
+            1.0 / self.frame_time
+        } else {
+            0.0
+        };
+        lines.push(format!("FPS {:.0} {:.1}MS", fps, self.frame_time * 1000.0));
+        lines.push(format!("QUEUE {}", ctx.render_queue_len()));
+        let text = lines.join("\n");
+
+        let style = TextStyle {
+            spacing: Spacing::Monospace,
+            ..TextStyle::small(Rgb(255, 255, 255))
+        };
+        let (width, height) = style.size(&text);
+        let padding = 0.125;
+        ctx.draw_rect_alpha(
+            Layer::Overlay,
+            Rgb(0, 0, 0),
+            160,
+            (0.0, 0.0),
+            (width + padding * 2.0, height + padding * 2.0),
+        );
+        ctx.draw_text(Layer::Overlay, (padding, padding), &text, &style);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    camera::VIEW_WIDTH,
+    ctx::{Ctx, Key, Layer, Rgb},
+    globals::GROUND_LEVEL,
+    locale::Message,
+    state::{Flag, State},
+    text::TextStyle,
+};
+
+const CHARACTERS_PER_SECOND: f64 = 24.0;
+const WRAP_WIDTH: f64 = 4.5;
+const PADDING: f64 = 0.125;
+const BORDER: f64 = 0.0625;
+const CHOICE_GAP: f64 = 0.5;
+
+#[derive(Clone, Copy)]
+pub enum Speaker {
+    Lemonhead,
+    Dad,
+    Child,
+}
+
+pub struct Line {
+    pub speaker: Speaker,
+    pub text: Message,
+    pub choices: &'static [Choice],
+}
+
+pub struct Choice {
+    pub text: Message,
+    pub flag: Option<Flag>,
+    pub next: &'static [Line],
+}
+
+pub const DAD_COINS: &[Line] = &[Line {
+    speaker: Speaker::Dad,
+    text: Message::DialogueDadCoins,
+    choices: &[],
+}];
+
+pub const CHILD_GREETING: &[Line] = &[Line {
+    speaker: Speaker::Child,
+    text: Message::DialogueChildGreeting,
+    choices: &[
+        Choice {
+            text: Message::DialogueChoiceSure,
+            flag: Some(Flag::PlayingHideAndSeek),
+            next: CHILD_COUNTING,
+        },
+        Choice {
+            text: Message::DialogueChoiceNotNow,
+            flag: None,
+            next: CHILD_SULKING,
+        },
+    ],
+}];
+
+const CHILD_COUNTING: &[Line] = &[Line {
+    speaker: Speaker::Child,
+    text: Message::DialogueChildCounting,
+    choices: &[],
+}];
+
+const CHILD_SULKING: &[Line] = &[
+    Line {
+        speaker: Speaker::Child,
+        text: Message::DialogueChildSulking,
+        choices: &[],
+    },
+    Line {
+        speaker: Speaker::Lemonhead,
+        text: Message::DialogueLemonheadSilence,
+        choices: &[],
+    },
+];
+
+pub struct Dialogue {
+    script: &'static [Line],
+    line: usize,
+    line_started: f64,
+    choice: usize,
+    npc_x: f64,
+}
+
+impl Dialogue {
+    pub fn new(script: &'static [Line], npc_x: f64, now: f64) -> Self {
+        Self {
+            script,
+            line: 0,
+            line_started: now,
+            choice: 0,
+            npc_x,
+        }
+    }
+
+    fn current(&self) -> &'static Line {
+        &self.script[self.line]
+    }
+
+    fn revealed(&self, now: f64) -> usize {
+        ((now - self.line_started) * CHARACTERS_PER_SECOND) as usize
+    }
+
+    pub fn update<C: Ctx>(mut self, ctx: &C, state: &mut State) -> Option<Self> {
+        let now = ctx.seconds_elapsed();
+        let line = self.current();
+        if ctx.key_down(Key::Left) {
+            self.choice = 0;
+        } else if ctx.key_down(Key::Right) {
+            self.choice = line.choices.len().saturating_sub(1);
+        }
+        if !ctx.key_down(Key::Interact) {
+            return Some(self);
+        }
+
+        let length = ctx.language().text(line.text).chars().count();
+        if self.revealed(now) < length {
+            self.line_started = now - length as f64 / CHARACTERS_PER_SECOND;
+            return Some(self);
+        }
+        if let Some(choice) = line.choices.get(self.choice) {
+            if let Some(flag) = choice.flag {
+                state.set_flag(flag);
+            }
+            self.script = choice.next;
+            self.line = 0;
+        } else {
+            self.line += 1;
+        }
+        self.line_started = now;
+        self.choice = 0;
+        (self.line < self.script.len()).then_some(self)
+    }
+
+    pub fn draw<C: Ctx>(&self, ctx: &mut C, lemonhead_x: f64) {
+        let line = self.current();
+        let language = ctx.language();
+        let style = TextStyle {
+            wrap_width: Some(WRAP_WIDTH),
+            ..TextStyle::small(Rgb(0, 0, 0))
+        };
+        let text = language.text(line.text);
+        let (mut width, mut height) = style.size(text);
+
+        let choices: Vec<&str> = line
+            .choices
+            .iter()
+            .map(|choice| language.text(choice.text))
+            .collect();
+        let choices_width = choices
+            .iter()
+            .map(|choice| style.measure(choice))
+            .sum::<f64>()
+            + CHOICE_GAP * choices.len().saturating_sub(1) as f64;
+        if !choices.is_empty() {
+            width = width.max(choices_width);
+            height += style.line_height() + style.cap_height();
+        }
+
+        let speaker_x = match line.speaker {
+            Speaker::Lemonhead => lemonhead_x,
+            Speaker::Dad | Speaker::Child => self.npc_x,
+        };
+        let (anchor_x, anchor_y) = ctx.camera().to_view((speaker_x + 0.5, GROUND_LEVEL));
+        let size = (width + PADDING * 2.0, height + PADDING * 2.0);
+        let position = (
+            (anchor_x - size.0 / 2.0).clamp(BORDER, VIEW_WIDTH - BORDER - size.0),
+            anchor_y - 0.25 - size.1,
+        );
+
+        ctx.draw_rect(
+            Layer::Ui,
+            Rgb(0, 0, 0),
+            (position.0 - BORDER, position.1 - BORDER),
+            (size.0 + BORDER * 2.0, size.1 + BORDER * 2.0),
+        );
+        ctx.draw_rect(Layer::Ui, Rgb(255, 255, 255), position, size);
+        if (position.0..position.0 + size.0).contains(&anchor_x) {
+            ctx.draw_rect(
+                Layer::Ui,
+                Rgb(0, 0, 0),
+                (anchor_x - BORDER, position.1 + size.1),
+                (BORDER * 2.0, 0.25 - BORDER),
+            );
+        }
+
+        let revealed = self.revealed(ctx.seconds_elapsed());
+        let mut remaining = revealed;
+        let lines: Vec<String> = style
+            .lines(text)
+            .into_iter()
+            .map(|line| {
+                let shown: String = line.chars().take(remaining).collect();
+                remaining = remaining.saturating_sub(line.chars().count() + 1);
+                shown
+            })
+            .collect();
+        let origin = (position.0 + PADDING, position.1 + PADDING);
+        ctx.draw_text(
+            Layer::Ui,
+            origin,
+            &lines.join("\n"),
+            &TextStyle {
+                wrap_width: None,
+                ..style
+            },
+        );
+
+        if revealed < text.chars().count() || choices.is_empty() {
+            return;
+        }
+        let y = origin.1 + height - style.cap_height();
+        let mut x = position.0 + (size.0 - choices_width) / 2.0;
+        for (index, choice) in choices.iter().enumerate() {
+            let color = if index == self.choice {
+                Rgb(0, 0, 0)
+            } else {
+                Rgb(170, 170, 170)
+            };
+            let style = TextStyle::small(color);
+            ctx.draw_text(Layer::Ui, (x, y), choice, &style);
+            x += style.measure(choice) + CHOICE_GAP;
+        }
+    }
+}
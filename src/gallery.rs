@@ -0,0 +1,196 @@
+use crate::{
+    camera::Camera,
+    ctx::{Ctx, Key, Layer, Rgb},
+    game::GameResult,
+    locale::Message,
+    profile::Profile,
+    sprite::Tile,
+    text::{Align, TextStyle},
+};
+
+const CARD_SIZE: (f64, f64) = (2.75, 4.0);
+const CARD_TOP: f64 = 2.25;
+const BORDER: f64 = 1.0 / 16.0;
+
+pub enum GalleryResult {
+    Replay(GameResult),
+    Back,
+    Quit,
+}
+
+struct Card {
+    ending: GameResult,
+    name: Message,
+    art: Tile,
+    art_size: (f64, f64),
+}
+
+const CARDS: [Card; 3] = [
+    Card {
+        ending: GameResult::GoodEnding,
+        name: Message::GalleryEscaped,
+        art: Tile::LemonCar0,
+        art_size: (2.0, 1.0),
+    },
+    Card {
+        ending: GameResult::Dead,
+        name: Message::GalleryDead,
+        art: Tile::Cross,
+        art_size: (1.0, 1.0),
+    },
+    Card {
+        ending: GameResult::Ascended,
+        name: Message::GalleryAscended,
+        art: Tile::LemonAngel0,
+        art_size: (1.0, 1.0),
+    },
+];
+
+fn format_time(seconds: f64) -> String {
+    let seconds = seconds as u64;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+fn draw_card<C: Ctx>(ctx: &mut C, card: &Card, x: f64, unlocked: bool, selected: bool) {
+    let border = if selected {
+        Rgb(230, 180, 0)
+    } else {
+        Rgb(0, 0, 0)
+    };
+    let fill = if unlocked {
+        Rgb(255, 250, 220)
+    } else {
+        Rgb(200, 200, 200)
+    };
+    let width = if selected { BORDER * 2.0 } else { BORDER };
+    ctx.draw_rect(Layer::Ui, border, (x, CARD_TOP), CARD_SIZE);
+    ctx.draw_rect(
+        Layer::Ui,
+        fill,
+        (x + width, CARD_TOP + width),
+        (CARD_SIZE.0 - width * 2.0, CARD_SIZE.1 - width * 2.0),
+    );
+
+    let center = x + CARD_SIZE.0 / 2.0;
+    let language = ctx.language();
+    if !unlocked {
+        ctx.draw_text(
+            Layer::Ui,
+            (center, CARD_TOP + 1.5),
+            language.text(Message::GalleryLocked),
+            &TextStyle {
+                align: Align::Center,
+                ..TextStyle::large(Rgb(110, 110, 110))
+            },
+        );
+        return;
+    }
+    ctx.draw_sprite(
+        Layer::Ui,
+        (center - card.art_size.0 / 2.0, CARD_TOP + 1.0),
+        card.art_size,
+        &card.art,
+    );
+    ctx.draw_text(
+        Layer::Ui,
+        (center, CARD_TOP + 3.0),
+        language.text(card.name),
+        &TextStyle {
+            align: Align::Center,
+            ..TextStyle::small(Rgb(0, 0, 0))
+        },
+    );
+}
+
+pub fn gallery<C: Ctx>(ctx: &mut C, profile: &Profile) -> Result<GalleryResult, C::Error> {
+    *ctx.camera_mut() = Camera::new();
+    let mut selected = 0;
+    let mut was_left = ctx.key_down(Key::Left);
+    let mut was_right = ctx.key_down(Key::Right);
+    loop {
+        ctx.setup()?;
+        if ctx.key_down(Key::Quit) {
+            break Ok(GalleryResult::Quit);
+        }
+        let left = ctx.key_down(Key::Left);
+        let right = ctx.key_down(Key::Right);
+        if left && !was_left {
+            selected = (selected + CARDS.len()) % (CARDS.len() + 1);
+        }
+        if right && !was_right {
+            selected = (selected + 1) % (CARDS.len() + 1);
+        }
+        (was_left, was_right) = (left, right);
+        if ctx.key_down(Key::Interact) {
+            match CARDS.get(selected) {
+                Some(card) if profile.unlocked(card.ending) => {
+                    break Ok(GalleryResult::Replay(card.ending));
+                }
+                Some(_) => {}
+                None => break Ok(GalleryResult::Back),
+            }
+        }
+
+        ctx.draw_background_fill(Rgb(255, 255, 255));
+        let language = ctx.language();
+        let heading = TextStyle {
+            pixel_size: 1.0 / 8.0,
+            align: Align::Center,
+            ..TextStyle::small(Rgb(0, 0, 0))
+        };
+        ctx.draw_text(
+            Layer::Ui,
+            (5.0, 0.75),
+            language.text(Message::GalleryTitle),
+            &heading,
+        );
+
+        let gap = (10.0 - CARD_SIZE.0 * CARDS.len() as f64) / (CARDS.len() + 1) as f64;
+        for (index, card) in CARDS.iter().enumerate() {
+            let x = gap + (CARD_SIZE.0 + gap) * index as f64;
+            draw_card(
+                ctx,
+                card,
+                x,
+                profile.unlocked(card.ending),
+                index == selected,
+            );
+        }
+
+        let first_completion = profile
+            .first_completion
+            .map_or_else(|| "-".to_owned(), format_time);
+        let stats = format!(
+            "{}: {}\n{}: {}",
+            language.text(Message::GalleryPlaythroughs),
+            profile.playthroughs,
+            language.text(Message::GalleryFirstCompletion),
+            first_completion,
+        );
+        ctx.draw_text(
+            Layer::Ui,
+            (5.0, 7.0),
+            &stats,
+            &TextStyle {
+                align: Align::Center,
+                ..TextStyle::small(Rgb(0, 0, 0))
+            },
+        );
+
+        let back = if selected == CARDS.len() {
+            Rgb(0, 0, 0)
+        } else {
+            Rgb(160, 160, 160)
+        };
+        ctx.draw_text(
+            Layer::Ui,
+            (5.0, 8.5),
+            language.text(Message::GalleryBack),
+            &TextStyle {
+                align: Align::Center,
+                ..TextStyle::large(back)
+            },
+        );
+        ctx.finish()?;
+    }
+}
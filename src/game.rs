@@ -1,62 +1,173 @@
 use crate::{
-    ctx::{Ctx, Key},
+    achievements::{Achievements, Event},
+    console::{Command, Console},
+    ctx::{Ctx, Key, Layer, Rgb},
+    cutscene::Cutscene,
+    debug::DebugOverlay,
     globals::GROUND_LEVEL,
-    scenes::{Scene, Scenes},
-    sprite::{self, ActorState, Text},
+    helper::CtxHelperExt,
+    hud::Hud,
+    inventory::ItemKind,
+    locale::Message,
+    physics::{self, GRAVITY, JUMP_SPEED},
+    scenes::Scenes,
+    speedrun::Speedrun,
+    sprite::{self, ActorState},
     state::{EndingChosen, State},
+    transition::{Transition, TransitionKind},
 };
 
+#[derive(Clone, Copy)]
 pub enum GameResult {
     GoodEnding,
     Dead,
+    Ascended,
     Quit,
 }
 
-struct Lemonhead {
-    x: f64,
-    y: f64,
-    state: ActorState,
+impl GameResult {
+    fn transition(&self) -> TransitionKind {
+        match self {
+            GameResult::GoodEnding => TransitionKind::Wipe,
+            GameResult::Ascended => TransitionKind::Fade(Rgb(255, 255, 255)),
+            GameResult::Dead | GameResult::Quit => TransitionKind::Fade(Rgb(0, 0, 0)),
+        }
+    }
+}
+
+pub struct Lemonhead {
+    pub x: f64,
+    pub y: f64,
+    pub velocity: f64,
+    pub state: ActorState,
+}
+
+impl Lemonhead {
+    pub fn height(&self) -> f64 {
+        GROUND_LEVEL - self.y
+    }
+}
+
+pub struct Start {
+    pub scene: Scenes,
+    pub position: f64,
+    pub state: State,
+}
+
+impl Start {
+    pub fn new() -> Self {
+        Self {
+            scene: Scenes::Tutorial,
+            position: 1.0,
+            state: State::new(),
+        }
+    }
+
+    fn checkpoint(scene: &Scenes, position: f64, state: &State) -> Self {
+        Self {
+            scene: scene.clone(),
+            position,
+            state: state.checkpoint(),
+        }
+    }
 }
 
-fn draw_interact_prompt<C: Ctx>(ctx: &mut C, state: &State<C>) {
+pub struct Outcome {
+    pub result: GameResult,
+    pub checkpoint: Start,
+}
+
+fn draw_interact_prompt<C: Ctx>(ctx: &mut C, state: &State) {
     let offset = (ctx.seconds_elapsed() * std::f64::consts::PI * 2.0).sin() * 0.05;
 
-    let text = if !state.living_room.has_escaped_dad {
-        Text::Space
-    } else if !state.kitchen.weapon_collected {
-        Text::SelfDefense
+    let message = if !state.living_room.has_escaped_dad {
+        Message::PromptSpace
+    } else if !state.inventory.has(ItemKind::Weapon) {
+        Message::PromptSelfDefense
     } else if !state.murder_living_room.dad_dead {
-        Text::NoWitnesses
+        Message::PromptNoWitnesses
     } else if !state.child_room.child_dead() {
-        Text::OneLeft
+        Message::PromptOneLeft
     } else if !state.child_room.child_stabs < 3 {
-        Text::More
+        Message::PromptMore
     } else {
-        Text::Ascend
+        Message::PromptAscend
     };
-
-    let centered = (10.0 - text.width()) / 2.0;
-
-    ctx.draw_sprite((centered, 9.0 + offset), (text.width(), 1.0), &text);
+    ctx.draw_caption(ctx.language().text(message), 9.5 + offset);
 }
 
-pub fn game<C: Ctx>(ctx: &mut C) -> Result<GameResult, C::Error> {
-    let mut scene = Scenes::Tutorial;
-    let mut state = State::new();
+pub fn game<C: Ctx>(
+    ctx: &mut C,
+    start: Start,
+    achievements: &mut Achievements,
+    speedrun: &mut Speedrun,
+) -> Result<Outcome, C::Error> {
+    let Start {
+        mut scene,
+        position,
+        mut state,
+    } = start;
     let mut lemonhead = Lemonhead {
-        x: 1.0,
+        x: position,
         y: GROUND_LEVEL,
+        velocity: 0.0,
         state: ActorState::Idle,
     };
-    ctx.set_music(crate::ctx::Music::Outside)?;
+    let mut checkpoint = Start::checkpoint(&scene, position, &state);
+    speedrun.checkpoint();
+    state.emit(Event::SceneEntered(scene.clone()));
+    scene.on_enter(ctx, &mut state)?;
+    ctx.camera_mut().enter_room(scene.width(), lemonhead.x);
     let mut elapsed_last_iter = ctx.seconds_elapsed();
+    let mut transition = Some(Transition::reveal(
+        TransitionKind::Fade(Rgb(0, 0, 0)),
+        elapsed_last_iter,
+    ));
+    let mut result = None;
+    let mut debug = DebugOverlay::new();
+    let mut console = Console::new();
+    let mut hud = Hud::new();
     loop {
         ctx.setup()?;
         if ctx.key_down(Key::Quit) {
-            break Ok(GameResult::Quit);
+            break Ok(Outcome {
+                result: GameResult::Quit,
+                checkpoint,
+            });
+        }
+        if let Some(command) = console.update(ctx) {
+            match command {
+                Command::Warp(new_scene, x) => state.scene_changed = Some((x, new_scene)),
+                Command::Set(field, value) => match state.set_field(&field, &value) {
+                    Ok(()) => console.print(format!("{field} = {value}")),
+                    Err(error) => console.print(error),
+                },
+                Command::Music(Some(music)) => ctx.set_music(music)?,
+                Command::Music(None) => ctx.stop_music()?,
+                Command::Ending(ending) => {
+                    transition = Some(Transition::cover(
+                        ending.transition(),
+                        ctx.seconds_elapsed(),
+                    ));
+                    result = Some(ending);
+                }
+                Command::Ascend => {
+                    state.ending_chosen = Some(EndingChosen::Ascended);
+                    state.cutscene = Some(Cutscene::ascension());
+                }
+                Command::TimeScale(scale) => ctx.set_time_scale(scale),
+            }
         }
         scene.draw(ctx, &state);
-        if scene.should_draw_interact_popup(&state, lemonhead.x) {
+        if !console.open() {
+            hud.update(ctx);
+        }
+        let talking = state.dialogue.is_some()
+            || state.scripted()
+            || transition.is_some()
+            || console.open()
+            || hud.inventory_open();
+        if !talking && scene.should_draw_interact_popup(&state, lemonhead.x, lemonhead.height()) {
             draw_interact_prompt(ctx, &state);
         }
 
@@ -64,57 +175,135 @@ pub fn game<C: Ctx>(ctx: &mut C) -> Result<GameResult, C::Error> {
 
         let delta_time = ctx.seconds_elapsed() - elapsed_last_iter;
         elapsed_last_iter = ctx.seconds_elapsed();
-        if ctx.key_down(Key::Left) && state.ending_chosen.is_none() && lemonhead.x > 0.0 {
-            lemonhead.x -= 1.25 * delta_time;
+        debug.update(ctx, delta_time);
+        if transition.is_none() && !console.open() && !hud.inventory_open() {
+            speedrun.tick(delta_time);
+        }
+        if let Some(mut cutscene) = state.cutscene.take() {
+            let paused = talking && !cutscene.blocking();
+            let delta_time = if paused { 0.0 } else { delta_time };
+            if let Some(ending) = cutscene.update(ctx, &mut state, &mut lemonhead, delta_time)? {
+                transition = Some(Transition::cover(
+                    ending.transition(),
+                    ctx.seconds_elapsed(),
+                ));
+                result = Some(ending);
+            }
+            if !cutscene.finished() {
+                state.cutscene = Some(cutscene);
+            }
+        }
+        if !console.open() {
+            if let Some(dialogue) = state.dialogue.take() {
+                state.dialogue = dialogue.update(ctx, &mut state);
+            }
+        }
+        if let Some(ref dialogue) = state.dialogue {
+            dialogue.draw(ctx, lemonhead.x);
+        }
+
+        let colliders = scene.colliders(&state);
+        let mut step = 0.0;
+        if !talking && ctx.key_down(Key::Left) && state.ending_chosen.is_none() {
+            step -= 1.25 * delta_time;
             lemonhead.state = ActorState::Left;
         }
 
-        if ctx.key_down(Key::Right) && state.ending_chosen.is_none() && lemonhead.x < 9.0 {
-            lemonhead.x += 1.25 * delta_time;
+        if !talking && ctx.key_down(Key::Right) && state.ending_chosen.is_none() {
+            step += 1.25 * delta_time;
             lemonhead.state = ActorState::Right;
         }
 
-        if ctx.key_down(Key::Interact) {
-            if let Some(item) = scene.closest_item_within_distance(&state, lemonhead.x) {
-                scene.interact(ctx, &mut state, item)?;
+        if step != 0.0 {
+            let (left, right) = scene.bounds();
+            let x = (lemonhead.x + step).clamp(left, right);
+            let height = lemonhead.height();
+            if !physics::blocked(&colliders, x, height)
+                || physics::blocked(&colliders, lemonhead.x, height)
+            {
+                lemonhead.x = x;
             }
         }
 
-        if let Some(ref ending) = state.ending_chosen {
-            match ending {
-                EndingChosen::Ascended => {
-                    lemonhead.y -= 0.25 * delta_time;
-                }
-                EndingChosen::Escaped => {
-                    lemonhead.state = ActorState::Left;
-                    lemonhead.x -= 0.5 * delta_time;
-                    if lemonhead.x < -1.0 {
-                        break Ok(GameResult::GoodEnding);
-                    }
-                }
+        if !state.scripted() && state.ending_chosen.is_none() {
+            let floor = physics::floor(&colliders, lemonhead.x, lemonhead.height());
+            let grounded = lemonhead.velocity == 0.0 && lemonhead.height() == floor;
+            if !talking && grounded && ctx.key_down(Key::Jump) {
+                lemonhead.velocity = JUMP_SPEED;
+            }
+            lemonhead.velocity -= GRAVITY * delta_time;
+            lemonhead.y -= lemonhead.velocity * delta_time;
+            if lemonhead.height() <= floor {
+                lemonhead.y = GROUND_LEVEL - floor;
+                lemonhead.velocity = 0.0;
             }
         }
 
+        if !talking && ctx.key_down(Key::Interact) {
+            scene.interact_closest(ctx, &mut state, lemonhead.x, lemonhead.height())?;
+        }
+
+        speedrun.sample(scene.name(), &lemonhead);
+        speedrun.draw_ghost(ctx, scene.name());
         let use_alt = ctx.seconds_elapsed() % 0.5 > 0.25;
         let lemon_sprite = sprite::Actor::lemonhead_sprite(&lemonhead.state, use_alt);
-        ctx.draw_sprite((lemonhead.x, lemonhead.y), (1.0, 1.0), &lemon_sprite);
-
-        match state.scene_changed {
-            None => (),
-            Some((position, new_scene)) => {
-                scene = new_scene;
-                lemonhead.x = position;
-                state.scene_changed = None;
+        ctx.draw_sprite(
+            Layer::Actors,
+            (lemonhead.x, lemonhead.y),
+            (1.0, 1.0),
+            &lemon_sprite,
+        );
+
+        if !talking {
+            if let Some(ending) = scene.update(ctx, &mut state, lemonhead.x, delta_time)? {
+                transition = Some(Transition::cover(
+                    ending.transition(),
+                    ctx.seconds_elapsed(),
+                ));
+                result = Some(ending);
             }
         }
 
-        if state.living_room.all_coins_collected() && !state.living_room.has_escaped_dad {
-            state.living_room.dad_attack_seconds += delta_time;
-            let dad_position = 13.65 - (state.living_room.dad_attack_seconds * 2.0);
-            if dad_position <= lemonhead.x {
-                break Ok(GameResult::Dead);
+        if state.scene_changed.is_some() && transition.is_none() {
+            transition = Some(Transition::cover(
+                TransitionKind::Iris,
+                ctx.seconds_elapsed(),
+            ));
+        }
+        if let Some(current) = transition.take() {
+            let now = ctx.seconds_elapsed();
+            current.draw(ctx, (lemonhead.x + 0.5, lemonhead.y + 0.5));
+            if !current.finished(now) {
+                transition = Some(current);
+            } else if current.covering() {
+                if let Some(result) = result {
+                    break Ok(Outcome { result, checkpoint });
+                }
+                if let Some((position, new_scene)) = state.scene_changed.take() {
+                    scene.on_exit(ctx, &mut state)?;
+                    speedrun.split(scene.name(), now);
+                    scene = new_scene;
+                    lemonhead.x = position;
+                    lemonhead.y = GROUND_LEVEL;
+                    lemonhead.velocity = 0.0;
+                    checkpoint = Start::checkpoint(&scene, position, &state);
+                    speedrun.checkpoint();
+                    ctx.camera_mut().enter_room(scene.width(), lemonhead.x);
+                    state.emit(Event::SceneEntered(scene.clone()));
+                    scene.on_enter(ctx, &mut state)?;
+                }
+                transition = Some(Transition::reveal(TransitionKind::Iris, now));
             }
         }
+        for event in std::mem::take(&mut state.events) {
+            achievements.handle(event, &state);
+        }
+        hud.draw(ctx, &scene, &state);
+        debug.draw(ctx, &scene, &state, &lemonhead);
+        console.draw(ctx);
+        speedrun.draw(ctx);
+        achievements.draw(ctx);
+        ctx.camera_mut().follow(lemonhead.x, delta_time);
         ctx.finish()?;
     }
 }
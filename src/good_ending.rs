@@ -1,50 +1,56 @@
 use std::f64::consts::PI;
 
 use crate::{
-    ctx::{Ctx, Key, Music, Rgb},
+    achievements::Achievements,
+    camera::Camera,
+    ctx::{Ctx, Key, Layer, Music, Rgb},
+    helper::CtxHelperExt,
+    lifecycle::EndingResult,
     sprite::Tile,
+    transition::{Transition, TransitionKind},
 };
 
-fn draw_layer_0<C: Ctx>(ctx: &mut C) {
-    let animation_timer = ctx.seconds_elapsed() % 10.0;
-
-    for i in 0..2 {
-        let position = -animation_timer + f64::from(i * 10);
-        ctx.draw_sprite((position, 1.0), (10.0, 9.0), &Tile::CityLayer0);
-    }
-}
-
-fn draw_layer_1<C: Ctx>(ctx: &mut C) {
-    let animation_timer = (ctx.seconds_elapsed() * 2.5) % 10.0;
-
-    for i in 0..2 {
-        let position = -animation_timer + f64::from(i * 10);
-        ctx.draw_sprite((position, 1.0), (10.0, 9.0), &Tile::CityLayer1);
-    }
-}
-
-fn draw_layer_2<C: Ctx>(ctx: &mut C) {
-    let animation_timer = ctx.seconds_elapsed() * 5.0 % 16.0;
-
-    for i in 0..3 {
-        let position = -animation_timer + f64::from(i * 16);
-        ctx.draw_sprite((position, 1.0), (16.0, 8.0), &Tile::CityLayer2);
-    }
-}
-
-pub fn good_ending<C: Ctx>(ctx: &mut C) -> Result<(), C::Error> {
+pub fn good_ending<C: Ctx>(
+    ctx: &mut C,
+    achievements: &mut Achievements,
+) -> Result<EndingResult, C::Error> {
     ctx.set_music(Music::Rich)?;
+    *ctx.camera_mut() = Camera::new();
+    let transition = Transition::reveal(TransitionKind::Wipe, ctx.seconds_elapsed());
 
     loop {
         ctx.setup()?;
-        if ctx.key_down(Key::Quit) || ctx.key_down(Key::Interact) {
-            break Ok(());
+        if ctx.key_down(Key::Quit) {
+            break Ok(EndingResult::Quit);
+        }
+        if ctx.key_down(Key::Interact) && transition.finished(ctx.seconds_elapsed()) {
+            break Ok(EndingResult::Menu);
         }
         ctx.draw_background_fill(Rgb(255, 255, 255));
+        ctx.camera_mut().x = ctx.seconds_elapsed() * 5.0;
+        let camera_x = ctx.camera().x;
 
-        draw_layer_0(ctx);
-        draw_layer_1(ctx);
-        draw_layer_2(ctx);
+        ctx.draw_parallax(
+            Layer::Wallpaper,
+            &Tile::CityLayer0,
+            (0.0, 1.0),
+            (10.0, 9.0),
+            0.2,
+        );
+        ctx.draw_parallax(
+            Layer::Wallpaper,
+            &Tile::CityLayer1,
+            (0.0, 1.0),
+            (10.0, 9.0),
+            0.5,
+        );
+        ctx.draw_parallax(
+            Layer::Wallpaper,
+            &Tile::CityLayer2,
+            (0.0, 1.0),
+            (16.0, 8.0),
+            1.0,
+        );
 
         let x_offset = (ctx.seconds_elapsed() % 1.0 * PI * 2.0).sin() * 0.125;
         let car = if ctx.seconds_elapsed() % 0.2 < 0.1 {
@@ -53,8 +59,15 @@ pub fn good_ending<C: Ctx>(ctx: &mut C) -> Result<(), C::Error> {
             Tile::LemonCar1
         };
 
-        ctx.draw_sprite((4.0 + x_offset, 8.0), (2.0, 1.0), &car);
-        ctx.draw_sprite((0.0, 9.0), (10.0, 1.0), &Tile::Ground);
+        ctx.draw_sprite(
+            Layer::Actors,
+            (camera_x + 4.0 + x_offset, 8.0),
+            (2.0, 1.0),
+            &car,
+        );
+        ctx.draw_sprite(Layer::Props, (camera_x, 9.0), (10.0, 1.0), &Tile::Ground);
+        transition.draw(ctx, (camera_x + 5.0, 8.5));
+        achievements.draw(ctx);
         ctx.finish()?;
     }
 }
@@ -1,37 +1,124 @@
 use std::f64::consts::PI;
 
 use crate::{
-    ctx::Ctx,
+    camera::VIEW_WIDTH,
+    ctx::{Ctx, Layer, Rgb},
     globals::GROUND_LEVEL,
+    inventory::{Inventory, Pickup},
     sprite::{Sprite, Tile},
+    text::{Align, TextStyle},
 };
 
 pub trait CtxHelperExt<C: Ctx> {
     fn draw_item(&mut self, sprite: &impl Sprite, position: f64);
+    fn draw_raised_item(&mut self, sprite: &impl Sprite, position: f64, height: f64);
+    fn draw_pickups(&mut self, inventory: &Inventory, pickups: &[(f64, Pickup)]);
     fn draw_ground(&mut self);
     fn draw_wallpaper(&mut self, sprite: &impl Sprite);
+    fn draw_parallax(
+        &mut self,
+        layer: Layer,
+        sprite: &impl Sprite,
+        position: (f64, f64),
+        size: (f64, f64),
+        factor: f64,
+    );
+    fn draw_caption(&mut self, text: &str, y: f64);
 }
 
 impl<C: Ctx> CtxHelperExt<C> for C {
     fn draw_item(&mut self, sprite: &impl Sprite, position: f64) {
-        let offset = (self.seconds_elapsed() * PI * 1.5).sin() * 0.125;
-        self.draw_sprite((position, GROUND_LEVEL + offset), (1.0, 1.0), sprite);
+        self.draw_raised_item(sprite, position, 0.0);
     }
 
-    fn draw_ground(&mut self) {
-        self.draw_sprite((0.0, GROUND_LEVEL + 1.0), (10.0, 1.0), &Tile::Ground);
+    fn draw_raised_item(&mut self, sprite: &impl Sprite, position: f64, height: f64) {
+        let offset = (self.seconds_elapsed() * PI * 1.5).sin() * 0.125;
         self.draw_sprite(
-            (0.0, GROUND_LEVEL + 2.0),
-            (10.0, 10.0 - GROUND_LEVEL - 2.0),
-            &Tile::Block,
+            Layer::Props,
+            (position, GROUND_LEVEL - height + offset),
+            (1.0, 1.0),
+            sprite,
         );
     }
 
+    fn draw_pickups(&mut self, inventory: &Inventory, pickups: &[(f64, Pickup)]) {
+        for (position, pickup) in pickups {
+            if !inventory.picked_up(*pickup) {
+                self.draw_item(&pickup.kind().sprite(), *position);
+            }
+        }
+    }
+
+    fn draw_ground(&mut self) {
+        let chunks = (self.camera().room_width() / VIEW_WIDTH).ceil() as u32;
+        for chunk in 0..chunks {
+            let x = f64::from(chunk) * VIEW_WIDTH;
+            self.draw_sprite(
+                Layer::Background,
+                (x, GROUND_LEVEL + 1.0),
+                (VIEW_WIDTH, 1.0),
+                &Tile::Ground,
+            );
+            self.draw_sprite(
+                Layer::Background,
+                (x, GROUND_LEVEL + 2.0),
+                (VIEW_WIDTH, 10.0 - GROUND_LEVEL - 2.0),
+                &Tile::Block,
+            );
+        }
+    }
+
     fn draw_wallpaper(&mut self, sprite: &impl Sprite) {
-        for x in 0..10 {
+        for x in 0..self.camera().room_width().ceil() as u32 {
             for y in 0..=GROUND_LEVEL as u32 {
-                self.draw_sprite((x as f64, y as f64), (1.0, 1.0), sprite);
+                self.draw_sprite(Layer::Wallpaper, (x as f64, y as f64), (1.0, 1.0), sprite);
             }
         }
     }
+
+    fn draw_parallax(
+        &mut self,
+        layer: Layer,
+        sprite: &impl Sprite,
+        position: (f64, f64),
+        size: (f64, f64),
+        factor: f64,
+    ) {
+        let camera_x = self.camera().x;
+        let scrolled = (camera_x * factor - position.0).rem_euclid(size.0);
+        let tiles = (VIEW_WIDTH / size.0).ceil() as u32 + 1;
+        for i in 0..tiles {
+            let x = camera_x - scrolled + f64::from(i) * size.0;
+            self.draw_sprite(layer, (x, position.1), size, sprite);
+        }
+    }
+
+    fn draw_caption(&mut self, text: &str, y: f64) {
+        let style = TextStyle {
+            align: Align::Center,
+            ..TextStyle::large(Rgb(255, 255, 255))
+        };
+        let (width, height) = style.size(text);
+        let padding = 0.125;
+        let border = 0.0625;
+        let position = (
+            VIEW_WIDTH / 2.0 - width / 2.0 - padding,
+            y - height / 2.0 - padding,
+        );
+        let size = (width + padding * 2.0, height + padding * 2.0);
+
+        self.draw_rect(
+            Layer::Ui,
+            Rgb(255, 255, 255),
+            (position.0 - border, position.1 - border),
+            (size.0 + border * 2.0, size.1 + border * 2.0),
+        );
+        self.draw_rect(Layer::Ui, Rgb(0, 0, 0), position, size);
+        self.draw_text(
+            Layer::Ui,
+            (VIEW_WIDTH / 2.0, position.1 + padding),
+            text,
+            &style,
+        );
+    }
 }
@@ -0,0 +1,191 @@
+use crate::{
+    camera::VIEW_WIDTH,
+    ctx::{Ctx, Key, Layer, Rgb},
+    inventory::{
+        Inventory, Pickup, BONUS_COINS, ENTRYWAY_COINS, ITEM_KINDS, KITCHEN_COINS,
+        LIVING_ROOM_COINS, TUTORIAL_COINS,
+    },
+    locale::Message,
+    scenes::Scenes,
+    sprite::Tile,
+    state::State,
+    text::{Align, TextStyle},
+};
+
+const ICON_SIZE: f64 = 0.5;
+const ROOM_COINS: [&[Pickup]; 4] = [
+    TUTORIAL_COINS,
+    ENTRYWAY_COINS,
+    KITCHEN_COINS,
+    LIVING_ROOM_COINS,
+];
+
+pub struct Hud {
+    visible: bool,
+    inventory_open: bool,
+}
+
+fn count(inventory: &Inventory, coins: &[Pickup]) -> (usize, usize) {
+    (inventory.collected(coins), coins.len())
+}
+
+impl Hud {
+    pub fn new() -> Self {
+        Self {
+            visible: true,
+            inventory_open: false,
+        }
+    }
+
+    pub fn inventory_open(&self) -> bool {
+        self.inventory_open
+    }
+
+    pub fn update<C: Ctx>(&mut self, ctx: &C) {
+        if ctx.key_down(Key::Hud) {
+            self.visible = !self.visible;
+        }
+        if ctx.key_down(Key::Inventory) {
+            self.inventory_open = !self.inventory_open;
+        }
+    }
+
+    pub fn draw<C: Ctx>(&self, ctx: &mut C, scene: &Scenes, state: &State) {
+        if self.inventory_open {
+            self.draw_inventory(ctx, &state.inventory);
+        }
+        if !self.visible {
+            return;
+        }
+        let rooms = ROOM_COINS.map(|coins| count(&state.inventory, coins));
+        let room = match scene {
+            Scenes::Tutorial => Some(rooms[0]),
+            Scenes::Entryway => Some(rooms[1]),
+            Scenes::Kitchen => Some(rooms[2]),
+            Scenes::LivingRoom => Some(rooms[3]),
+            Scenes::MurderLivingRoom | Scenes::Outside | Scenes::ChildRoom => None,
+        };
+        let bonus = count(&state.inventory, BONUS_COINS);
+        let total = rooms
+            .iter()
+            .chain([&bonus])
+            .fold((0, 0), |(collected, all), room| {
+                (collected + room.0, all + room.1)
+            });
+
+        let style = TextStyle::small(Rgb(255, 255, 255));
+        let text = match room {
+            Some((collected, all)) => format!("{collected}/{all} ({}/{})", total.0, total.1),
+            None => format!("{}/{}", total.0, total.1),
+        };
+        let items: Vec<Tile> = ITEM_KINDS
+            .into_iter()
+            .filter(|kind| !kind.consumable() && state.inventory.has(*kind))
+            .map(|kind| kind.sprite())
+            .collect();
+
+        let padding = 0.125;
+        let text_width = style.measure(&text);
+        let width =
+            padding * 3.0 + ICON_SIZE + text_width + items.len() as f64 * (ICON_SIZE + padding);
+        let height = ICON_SIZE + padding * 2.0;
+        let (x, y) = (padding, VIEW_WIDTH - height - padding);
+        ctx.draw_rect_alpha(Layer::Ui, Rgb(0, 0, 0), 160, (x, y), (width, height));
+        ctx.draw_sprite(
+            Layer::Ui,
+            (x + padding, y + padding),
+            (ICON_SIZE, ICON_SIZE),
+            &Tile::Coin,
+        );
+        let text_x = x + padding * 2.0 + ICON_SIZE;
+        ctx.draw_text(
+            Layer::Ui,
+            (text_x, y + (height - style.cap_height()) / 2.0),
+            &text,
+            &style,
+        );
+        let mut icon_x = text_x + text_width + padding;
+        for item in &items {
+            ctx.draw_sprite(
+                Layer::Ui,
+                (icon_x, y + padding),
+                (ICON_SIZE, ICON_SIZE),
+                item,
+            );
+            icon_x += ICON_SIZE + padding;
+        }
+    }
+
+    fn draw_inventory<C: Ctx>(&self, ctx: &mut C, inventory: &Inventory) {
+        let language = ctx.language();
+        let title = TextStyle {
+            align: Align::Center,
+            ..TextStyle::large(Rgb(255, 255, 255))
+        };
+        let style = TextStyle::small(Rgb(255, 255, 255));
+        let held: Vec<_> = ITEM_KINDS
+            .into_iter()
+            .filter(|kind| inventory.has(*kind))
+            .collect();
+
+        let padding = 0.25;
+        let row_height = ICON_SIZE + padding;
+        let width = 6.0;
+        let height = title.cap_height() + padding * 3.0 + row_height * held.len().max(1) as f64;
+        let (x, y) = ((VIEW_WIDTH - width) / 2.0, 2.0);
+        ctx.draw_rect_alpha(Layer::Overlay, Rgb(0, 0, 0), 200, (x, y), (width, height));
+        ctx.draw_text(
+            Layer::Overlay,
+            (VIEW_WIDTH / 2.0, y + padding),
+            language.text(Message::InventoryTitle),
+            &title,
+        );
+
+        let mut row_y = y + title.cap_height() + padding * 2.0;
+        if held.is_empty() {
+            ctx.draw_text(
+                Layer::Overlay,
+                (
+                    VIEW_WIDTH / 2.0,
+                    row_y + (ICON_SIZE - style.cap_height()) / 2.0,
+                ),
+                language.text(Message::InventoryEmpty),
+                &TextStyle {
+                    align: Align::Center,
+                    ..style
+                },
+            );
+            return;
+        }
+        for kind in held {
+            ctx.draw_sprite(
+                Layer::Overlay,
+                (x + padding, row_y),
+                (ICON_SIZE, ICON_SIZE),
+                &kind.sprite(),
+            );
+            let text_y = row_y + (ICON_SIZE - style.cap_height()) / 2.0;
+            ctx.draw_text(
+                Layer::Overlay,
+                (x + padding * 2.0 + ICON_SIZE, text_y),
+                language.text(kind.name()),
+                &style,
+            );
+            let amount = if kind.consumable() {
+                format!("x{}", inventory.count(kind))
+            } else {
+                language.text(Message::InventoryKeyItem).to_owned()
+            };
+            ctx.draw_text(
+                Layer::Overlay,
+                (x + width - padding, text_y),
+                &amount,
+                &TextStyle {
+                    align: Align::Right,
+                    ..style
+                },
+            );
+            row_y += row_height;
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{locale::Message, sprite::Tile};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ItemKind {
+    Coin,
+    Key,
+    Weapon,
+}
+
+pub const ITEM_KINDS: [ItemKind; 3] = [ItemKind::Coin, ItemKind::Key, ItemKind::Weapon];
+
+impl ItemKind {
+    pub fn consumable(&self) -> bool {
+        match self {
+            ItemKind::Coin => true,
+            ItemKind::Key | ItemKind::Weapon => false,
+        }
+    }
+
+    pub fn sprite(&self) -> Tile {
+        match self {
+            ItemKind::Coin => Tile::Coin,
+            ItemKind::Key => Tile::Key,
+            ItemKind::Weapon => Tile::Weapon,
+        }
+    }
+
+    pub fn name(&self) -> Message {
+        match self {
+            ItemKind::Coin => Message::ItemCoin,
+            ItemKind::Key => Message::ItemKey,
+            ItemKind::Weapon => Message::ItemWeapon,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Pickup {
+    TutorialCoin,
+    EntrywayCoin(u8),
+    KitchenCoin(u8),
+    LivingRoomCoin(u8),
+    BonusCoin(u8),
+    OutsideKey,
+    KitchenWeapon,
+}
+
+pub const TUTORIAL_COINS: &[Pickup] = &[Pickup::TutorialCoin];
+pub const ENTRYWAY_COINS: &[Pickup] = &[
+    Pickup::EntrywayCoin(0),
+    Pickup::EntrywayCoin(1),
+    Pickup::EntrywayCoin(2),
+    Pickup::EntrywayCoin(3),
+];
+pub const KITCHEN_COINS: &[Pickup] = &[
+    Pickup::KitchenCoin(0),
+    Pickup::KitchenCoin(1),
+    Pickup::KitchenCoin(2),
+];
+pub const LIVING_ROOM_COINS: &[Pickup] = &[Pickup::LivingRoomCoin(0), Pickup::LivingRoomCoin(1)];
+pub const BONUS_COINS: &[Pickup] = &[Pickup::BonusCoin(0), Pickup::BonusCoin(1)];
+const KEY_ITEMS: &[Pickup] = &[Pickup::OutsideKey, Pickup::KitchenWeapon];
+
+impl Pickup {
+    pub fn kind(&self) -> ItemKind {
+        match self {
+            Pickup::TutorialCoin
+            | Pickup::EntrywayCoin(_)
+            | Pickup::KitchenCoin(_)
+            | Pickup::LivingRoomCoin(_)
+            | Pickup::BonusCoin(_) => ItemKind::Coin,
+            Pickup::OutsideKey => ItemKind::Key,
+            Pickup::KitchenWeapon => ItemKind::Weapon,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Pickup::TutorialCoin => "tutorial.coin".to_owned(),
+            Pickup::EntrywayCoin(index) => format!("entryway.coins.{index}"),
+            Pickup::KitchenCoin(index) => format!("kitchen.coins.{index}"),
+            Pickup::LivingRoomCoin(index) => format!("living_room.coins.{index}"),
+            Pickup::BonusCoin(index) => format!("bonus.coins.{index}"),
+            Pickup::OutsideKey => "outside.key_collected".to_owned(),
+            Pickup::KitchenWeapon => "kitchen.weapon_collected".to_owned(),
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        [
+            TUTORIAL_COINS,
+            ENTRYWAY_COINS,
+            KITCHEN_COINS,
+            LIVING_ROOM_COINS,
+            BONUS_COINS,
+            KEY_ITEMS,
+        ]
+        .concat()
+        .into_iter()
+        .find(|pickup| pickup.name() == name)
+    }
+}
+
+pub enum Requirement {
+    Has(ItemKind),
+    AllOf(&'static [Pickup]),
+}
+
+#[derive(Clone)]
+pub struct Inventory {
+    picked_up: Vec<Pickup>,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self {
+            picked_up: Vec::new(),
+        }
+    }
+
+    pub fn pick_up(&mut self, pickup: Pickup) {
+        if !self.picked_up(pickup) {
+            self.picked_up.push(pickup);
+        }
+    }
+
+    pub fn put_back(&mut self, pickup: Pickup) {
+        self.picked_up.retain(|other| *other != pickup);
+    }
+
+    pub fn picked_up(&self, pickup: Pickup) -> bool {
+        self.picked_up.contains(&pickup)
+    }
+
+    pub fn count(&self, kind: ItemKind) -> usize {
+        self.picked_up
+            .iter()
+            .filter(|pickup| pickup.kind() == kind)
+            .count()
+    }
+
+    pub fn has(&self, kind: ItemKind) -> bool {
+        self.count(kind) > 0
+    }
+
+    pub fn collected(&self, pickups: &[Pickup]) -> usize {
+        pickups
+            .iter()
+            .filter(|pickup| self.picked_up(**pickup))
+            .count()
+    }
+
+    pub fn meets(&self, requirement: &Requirement) -> bool {
+        match requirement {
+            Requirement::Has(kind) => self.has(*kind),
+            Requirement::AllOf(pickups) => self.collected(pickups) == pickups.len(),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    achievements::Achievements,
+    ascension_ending::ascension_ending,
+    ctx::Ctx,
+    dead_ending::dead_ending,
+    gallery::{gallery, GalleryResult},
+    game::{game, GameResult, Start},
+    good_ending::good_ending,
+    menu::{menu, MenuResult},
+    profile::Profile,
+    speedrun::Speedrun,
+};
+
+pub enum EndingResult {
+    Retry,
+    Menu,
+    Quit,
+}
+
+pub enum Phase {
+    Menu,
+    Gallery,
+    NewRun(Start),
+    Game(Start),
+    Ending(GameResult, Start),
+    Replay(GameResult),
+    Preview(GameResult),
+}
+
+fn play_ending<C: Ctx>(
+    ctx: &mut C,
+    result: GameResult,
+    achievements: &mut Achievements,
+) -> Result<EndingResult, C::Error> {
+    match result {
+        GameResult::GoodEnding => good_ending(ctx, achievements),
+        GameResult::Dead => dead_ending(ctx, achievements),
+        GameResult::Ascended => ascension_ending(ctx, achievements),
+        GameResult::Quit => Ok(EndingResult::Quit),
+    }
+}
+
+pub fn run<C: Ctx>(ctx: &mut C, mut phase: Phase, timer: bool) -> Result<(), C::Error> {
+    let mut profile = Profile::load();
+    let mut achievements = Achievements::load();
+    let mut speedrun = Speedrun::new(timer);
+    let mut run_started = ctx.seconds_elapsed();
+    loop {
+        phase = match phase {
+            Phase::Menu => match menu(ctx)? {
+                MenuResult::Start => Phase::NewRun(Start::new()),
+                MenuResult::Gallery => Phase::Gallery,
+                MenuResult::Quit => break Ok(()),
+            },
+            Phase::Gallery => match gallery(ctx, &profile)? {
+                GalleryResult::Replay(result) => Phase::Replay(result),
+                GalleryResult::Back => Phase::Menu,
+                GalleryResult::Quit => break Ok(()),
+            },
+            Phase::NewRun(start) => {
+                run_started = ctx.seconds_elapsed();
+                achievements.start_run();
+                speedrun.restart();
+                Phase::Game(start)
+            }
+            Phase::Game(start) => {
+                let outcome = game(ctx, start, &mut achievements, &mut speedrun)?;
+                match outcome.result {
+                    GameResult::Quit => break Ok(()),
+                    result => {
+                        let seconds = ctx.seconds_elapsed() - run_started;
+                        profile.record(result, seconds);
+                        if let Err(error) = profile.save() {
+                            eprintln!("unable to save profile: {error}");
+                        }
+                        achievements.finish_run(result, speedrun.time(), &profile);
+                        speedrun.finish(result, ctx.seconds_elapsed());
+                        Phase::Ending(result, outcome.checkpoint)
+                    }
+                }
+            }
+            Phase::Replay(result) => match play_ending(ctx, result, &mut achievements)? {
+                EndingResult::Retry | EndingResult::Menu => Phase::Gallery,
+                EndingResult::Quit => break Ok(()),
+            },
+            Phase::Preview(result) => match play_ending(ctx, result, &mut achievements)? {
+                EndingResult::Retry | EndingResult::Menu => Phase::Menu,
+                EndingResult::Quit => break Ok(()),
+            },
+            Phase::Ending(result, checkpoint) => {
+                let choice = play_ending(ctx, result, &mut achievements)?;
+                match choice {
+                    EndingResult::Retry => {
+                        speedrun.retry();
+                        Phase::Game(checkpoint)
+                    }
+                    EndingResult::Menu => Phase::Menu,
+                    EndingResult::Quit => break Ok(()),
+                }
+            }
+        };
+    }
+}
@@ -0,0 +1,293 @@
+#[derive(Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    Danish,
+    Russian,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Message {
+    PromptSpace,
+    PromptSelfDefense,
+    PromptNoWitnesses,
+    PromptOneLeft,
+    PromptMore,
+    PromptAscend,
+    TutorialWelcome,
+    TutorialGoals,
+    TutorialRemember,
+    TutorialVoices,
+    MenuTitle,
+    MenuStart,
+    MenuGallery,
+    GalleryTitle,
+    GalleryEscaped,
+    GalleryDead,
+    GalleryAscended,
+    GalleryLocked,
+    GalleryPlaythroughs,
+    GalleryFirstCompletion,
+    GalleryBack,
+    AchievementUnlocked,
+    AchievementEntrywayCoins,
+    AchievementKitchenCoins,
+    AchievementLivingRoomCoins,
+    AchievementPacifist,
+    AchievementSpeedrunner,
+    AchievementCompletionist,
+    AchievementNoLookingBack,
+    InventoryTitle,
+    InventoryEmpty,
+    InventoryKeyItem,
+    ItemCoin,
+    ItemKey,
+    ItemWeapon,
+    DeadCaption,
+    DeadRetry,
+    DeadMenu,
+    AscendedCaption,
+    DialogueDadCoins,
+    DialogueChildGreeting,
+    DialogueChoiceSure,
+    DialogueChoiceNotNow,
+    DialogueChildCounting,
+    DialogueChildSulking,
+    DialogueLemonheadSilence,
+    CutsceneEscape,
+}
+
+const ENGLISH: &[(Message, &str)] = &[
+    (Message::PromptSpace, "SPACE"),
+    (Message::PromptSelfDefense, "SELF DEFENSE"),
+    (Message::PromptNoWitnesses, "NO WITNESSES"),
+    (Message::PromptOneLeft, "1 LEFT"),
+    (Message::PromptMore, "MORE"),
+    (Message::PromptAscend, "ASCEND"),
+    (
+        Message::TutorialWelcome,
+        "WELCOME, LEMON!\nYOUR GOAL IS TO:",
+    ),
+    (
+        Message::TutorialGoals,
+        "1. COLLECT COINS WITH [SPACE]\n2. MOVE TO NEXT AREA WITH [A]+[D]",
+    ),
+    (
+        Message::TutorialRemember,
+        "REMEMBER TO HAVE FUN AND\nLISTEN TO THE VOICES! :)",
+    ),
+    (Message::TutorialVoices, "<- THE\nVOICES"),
+    (Message::MenuTitle, "THE\nADVENTURES\nOF..."),
+    (Message::MenuStart, "PUSH [SPACE]\nTO START"),
+    (Message::MenuGallery, "[D] ENDINGS"),
+    (Message::GalleryTitle, "ENDINGS"),
+    (Message::GalleryEscaped, "ESCAPED"),
+    (Message::GalleryDead, "DEAD"),
+    (Message::GalleryAscended, "ASCENDED"),
+    (Message::GalleryLocked, "???"),
+    (Message::GalleryPlaythroughs, "PLAYTHROUGHS"),
+    (Message::GalleryFirstCompletion, "FIRST CLEAR"),
+    (Message::GalleryBack, "BACK"),
+    (Message::AchievementUnlocked, "ACHIEVEMENT UNLOCKED"),
+    (Message::AchievementEntrywayCoins, "PENNY PINCHER"),
+    (Message::AchievementKitchenCoins, "KITCHEN RAID"),
+    (Message::AchievementLivingRoomCoins, "DAD'S SAVINGS"),
+    (Message::AchievementPacifist, "CLEAN HANDS"),
+    (Message::AchievementSpeedrunner, "IN A HURRY"),
+    (Message::AchievementCompletionist, "SEEN IT ALL"),
+    (Message::AchievementNoLookingBack, "NO LOOKING BACK"),
+    (Message::InventoryTitle, "INVENTORY"),
+    (Message::InventoryEmpty, "EMPTY"),
+    (Message::InventoryKeyItem, "KEY ITEM"),
+    (Message::ItemCoin, "COINS"),
+    (Message::ItemKey, "KEY"),
+    (Message::ItemWeapon, "WEAPON"),
+    (Message::DeadCaption, "GAME\nOVER!"),
+    (Message::DeadRetry, "RETRY"),
+    (Message::DeadMenu, "MENU"),
+    (Message::AscendedCaption, "ASCENDED."),
+    (Message::DialogueDadCoins, "HEY! THOSE ARE MY COINS!"),
+    (
+        Message::DialogueChildGreeting,
+        "BIG BROTHER! WANNA PLAY HIDE AND SEEK?",
+    ),
+    (Message::DialogueChoiceSure, "SURE"),
+    (Message::DialogueChoiceNotNow, "NOT NOW"),
+    (
+        Message::DialogueChildCounting,
+        "YAY! I'LL CLOSE MY EYES AND COUNT TO TEN!",
+    ),
+    (Message::DialogueChildSulking, "AW... YOU'RE NO FUN."),
+    (Message::DialogueLemonheadSilence, "..."),
+    (Message::CutsceneEscape, "SO LONG, SUCKERS!"),
+];
+
+const DANISH: &[(Message, &str)] = &[
+    (Message::PromptSpace, "MELLEMRUM"),
+    (Message::PromptSelfDefense, "SELVFORSVAR"),
+    (Message::PromptNoWitnesses, "INGEN VIDNER"),
+    (Message::PromptOneLeft, "1 TILBAGE"),
+    (Message::PromptMore, "MERE"),
+    (Message::PromptAscend, "STIG OP"),
+    (
+        Message::TutorialWelcome,
+        "VELKOMMEN, CITRON!\nDIT MÅL ER AT:",
+    ),
+    (
+        Message::TutorialGoals,
+        "1. SAML MØNTER MED [MELLEMRUM]\n2. GÅ TIL NÆSTE OMRÅDE MED [A]+[D]",
+    ),
+    (
+        Message::TutorialRemember,
+        "HUSK AT HAVE DET SJOVT OG\nLYT TIL STEMMERNE! :)",
+    ),
+    (Message::TutorialVoices, "<- STEM-\nMERNE"),
+    (Message::MenuTitle, "EVENTYRET\nOM..."),
+    (Message::MenuStart, "TRYK [MELLEMRUM]\nFOR AT STARTE"),
+    (Message::MenuGallery, "[D] SLUTNINGER"),
+    (Message::GalleryTitle, "SLUTNINGER"),
+    (Message::GalleryEscaped, "FLYGTET"),
+    (Message::GalleryDead, "DØD"),
+    (Message::GalleryAscended, "OPSTEGET"),
+    (Message::GalleryLocked, "???"),
+    (Message::GalleryPlaythroughs, "GENNEMSPILNINGER"),
+    (Message::GalleryFirstCompletion, "FØRSTE GANG"),
+    (Message::GalleryBack, "TILBAGE"),
+    (Message::AchievementUnlocked, "PRÆSTATION LÅST OP"),
+    (Message::AchievementEntrywayCoins, "PENGEPUGER"),
+    (Message::AchievementKitchenCoins, "KØKKENRAZZIA"),
+    (Message::AchievementLivingRoomCoins, "FARS OPSPARING"),
+    (Message::AchievementPacifist, "RENE HÆNDER"),
+    (Message::AchievementSpeedrunner, "TRAVLT"),
+    (Message::AchievementCompletionist, "SET DET HELE"),
+    (Message::AchievementNoLookingBack, "INTET TILBAGEBLIK"),
+    (Message::InventoryTitle, "INVENTAR"),
+    (Message::InventoryEmpty, "TOM"),
+    (Message::InventoryKeyItem, "NØGLEGENSTAND"),
+    (Message::ItemCoin, "MØNTER"),
+    (Message::ItemKey, "NØGLE"),
+    (Message::ItemWeapon, "VÅBEN"),
+    (Message::DeadCaption, "SPILLET\nER SLUT!"),
+    (Message::DeadRetry, "PRØV IGEN"),
+    (Message::DeadMenu, "MENU"),
+    (Message::AscendedCaption, "OPSTEGET."),
+    (Message::DialogueDadCoins, "HEY! DET ER MINE MØNTER!"),
+    (
+        Message::DialogueChildGreeting,
+        "STORE BROR! SKAL VI LEGE GEMMELEG?",
+    ),
+    (Message::DialogueChoiceSure, "JA DA"),
+    (Message::DialogueChoiceNotNow, "IKKE NU"),
+    (
+        Message::DialogueChildCounting,
+        "JUBII! JEG LUKKER ØJNENE OG TÆLLER TIL TI!",
+    ),
+    (Message::DialogueChildSulking, "ØV... DU ER KEDELIG."),
+    (Message::DialogueLemonheadSilence, "..."),
+    (Message::CutsceneEscape, "FARVEL, TABERE!"),
+];
+
+const RUSSIAN: &[(Message, &str)] = &[
+    (Message::PromptSpace, "ПРОБЕЛ"),
+    (Message::PromptSelfDefense, "САМООБОРОНА"),
+    (Message::PromptNoWitnesses, "БЕЗ СВИДЕТЕЛЕЙ"),
+    (Message::PromptOneLeft, "ОСТАЛСЯ 1"),
+    (Message::PromptMore, "ЕЩЁ"),
+    (Message::PromptAscend, "ВОЗНЕСИСЬ"),
+    (
+        Message::TutorialWelcome,
+        "ДОБРО ПОЖАЛОВАТЬ, ЛИМОН!\nТВОЯ ЦЕЛЬ:",
+    ),
+    (
+        Message::TutorialGoals,
+        "1. СОБИРАЙ МОНЕТЫ [ПРОБЕЛОМ]\n2. ИДИ ДАЛЬШЕ С [A]+[D]",
+    ),
+    (
+        Message::TutorialRemember,
+        "НЕ ЗАБУДЬ ВЕСЕЛИТЬСЯ И\nСЛУШАТЬ ГОЛОСА! :)",
+    ),
+    (Message::TutorialVoices, "<- ГОЛОСА"),
+    (Message::MenuTitle, "ПРИКЛЮЧЕНИЯ\n..."),
+    (Message::MenuStart, "НАЖМИ [ПРОБЕЛ]\nЧТОБЫ НАЧАТЬ"),
+    (Message::MenuGallery, "[D] КОНЦОВКИ"),
+    (Message::GalleryTitle, "КОНЦОВКИ"),
+    (Message::GalleryEscaped, "СБЕЖАЛ"),
+    (Message::GalleryDead, "МЁРТВ"),
+    (Message::GalleryAscended, "ВОЗНЁССЯ"),
+    (Message::GalleryLocked, "???"),
+    (Message::GalleryPlaythroughs, "ПРОХОЖДЕНИЯ"),
+    (Message::GalleryFirstCompletion, "ПЕРВЫЙ РАЗ"),
+    (Message::GalleryBack, "НАЗАД"),
+    (Message::AchievementUnlocked, "ДОСТИЖЕНИЕ ПОЛУЧЕНО"),
+    (Message::AchievementEntrywayCoins, "КОПИЛКА"),
+    (Message::AchievementKitchenCoins, "НАБЕГ НА КУХНЮ"),
+    (Message::AchievementLivingRoomCoins, "ПАПИНЫ СБЕРЕЖЕНИЯ"),
+    (Message::AchievementPacifist, "ЧИСТЫЕ РУКИ"),
+    (Message::AchievementSpeedrunner, "В СПЕШКЕ"),
+    (Message::AchievementCompletionist, "ВСЁ ВИДЕЛ"),
+    (Message::AchievementNoLookingBack, "НИ ШАГУ НАЗАД"),
+    (Message::InventoryTitle, "ИНВЕНТАРЬ"),
+    (Message::InventoryEmpty, "ПУСТО"),
+    (Message::InventoryKeyItem, "ВАЖНЫЙ ПРЕДМЕТ"),
+    (Message::ItemCoin, "МОНЕТЫ"),
+    (Message::ItemKey, "КЛЮЧ"),
+    (Message::ItemWeapon, "ОРУЖИЕ"),
+    (Message::DeadCaption, "ИГРА\nОКОНЧЕНА!"),
+    (Message::DeadRetry, "ЗАНОВО"),
+    (Message::DeadMenu, "МЕНЮ"),
+    (Message::AscendedCaption, "ВОЗНЕСЕНИЕ."),
+    (Message::DialogueDadCoins, "ЭЙ! ЭТО МОИ МОНЕТЫ!"),
+    (
+        Message::DialogueChildGreeting,
+        "БРАТИК! ДАВАЙ ИГРАТЬ В ПРЯТКИ?",
+    ),
+    (Message::DialogueChoiceSure, "ДАВАЙ"),
+    (Message::DialogueChoiceNotNow, "НЕ СЕЙЧАС"),
+    (
+        Message::DialogueChildCounting,
+        "УРА! Я ЗАКРОЮ ГЛАЗА И ПОСЧИТАЮ ДО ДЕСЯТИ!",
+    ),
+    (Message::DialogueChildSulking, "ЭХ... С ТОБОЙ СКУЧНО."),
+    (Message::DialogueLemonheadSilence, "..."),
+    (Message::CutsceneEscape, "ПОКА, НЕУДАЧНИКИ!"),
+];
+
+impl Language {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "en" => Some(Language::English),
+            "da" => Some(Language::Danish),
+            "ru" => Some(Language::Russian),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Danish => "da",
+            Language::Russian => "ru",
+        }
+    }
+
+    pub fn detect() -> Self {
+        std::env::var("LANG")
+            .ok()
+            .and_then(|lang| lang.get(..2).and_then(Language::parse))
+            .unwrap_or(Language::English)
+    }
+
+    pub fn text(&self, message: Message) -> &'static str {
+        let table = match self {
+            Language::English => ENGLISH,
+            Language::Danish => DANISH,
+            Language::Russian => RUSSIAN,
+        };
+        let find = |table: &[(Message, &'static str)]| {
+            table
+                .iter()
+                .find(|(key, _)| *key == message)
+                .map(|(_, text)| *text)
+        };
+        find(table).or_else(|| find(ENGLISH)).unwrap_or_default()
+    }
+}
@@ -1,32 +1,73 @@
 #![warn(clippy::unwrap_used)]
+mod achievements;
+mod ascension_ending;
+mod camera;
+mod cli;
+mod console;
 mod ctx;
+mod cutscene;
 mod dead_ending;
+mod debug;
+mod dialogue;
+mod gallery;
 mod game;
 mod globals;
 mod good_ending;
 mod helper;
+mod hud;
+mod inventory;
+mod lifecycle;
+mod locale;
 mod menu;
+mod physics;
+mod profile;
+mod replay;
 mod scenes;
 mod sdl_rodio_ctx;
+mod settings;
+mod speedrun;
 mod sprite;
 mod state;
+mod storage;
+mod text;
+mod transition;
 
-use dead_ending::dead_ending;
-use game::game;
-use good_ending::good_ending;
-use menu::menu;
+use cli::{Ending, Options};
+use game::{GameResult, Start};
+use lifecycle::Phase;
+use scenes::Scenes;
 use sdl_rodio_ctx::SdlRodioCtx;
+use settings::Settings;
+use state::State;
 
 fn main() -> Result<(), String> {
-    let mut ctx = SdlRodioCtx::new()?;
-
-    if let menu::MenuResult::Quit = menu(&mut ctx)? {
+    let settings = Settings::load();
+    let options = Options::parse(std::env::args().skip(1))?;
+    if options.help {
+        println!("{}", cli::USAGE);
         return Ok(());
-    };
-    let result = game(&mut ctx)?;
-    match result {
-        game::GameResult::GoodEnding => good_ending(&mut ctx),
-        game::GameResult::Dead => dead_ending(&mut ctx),
-        game::GameResult::Quit => Ok(()),
     }
+    let phase = match options.ending {
+        Some(Ending::Good) => Phase::Preview(GameResult::GoodEnding),
+        Some(Ending::Dead) => Phase::Preview(GameResult::Dead),
+        Some(Ending::Ascend) => Phase::Preview(GameResult::Ascended),
+        None if options.scene.is_some() || options.state.is_some() => {
+            let scene = match &options.scene {
+                Some(name) => Scenes::parse(name).ok_or(format!("unknown scene '{name}'"))?,
+                None => Scenes::Tutorial,
+            };
+            let state = match &options.state {
+                Some(path) => State::load(path)?,
+                None => State::new(),
+            };
+            Phase::NewRun(Start {
+                scene,
+                position: 1.0,
+                state,
+            })
+        }
+        None => Phase::Menu,
+    };
+    let timer = options.overrides.apply(&settings).speedrun_timer;
+    SdlRodioCtx::run(settings, &options, |ctx| lifecycle::run(ctx, phase, timer))
 }
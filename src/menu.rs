@@ -1,26 +1,41 @@
 use std::f64::consts::PI;
 
 use crate::{
-    ctx::{Ctx, Music, Rgb},
-    sprite::{Actor, ActorState, Tile},
+    camera::Camera,
+    ctx::{Ctx, Key, Layer, Music, Rgb},
+    locale::Message,
+    sprite::{Actor, ActorState, Logo, Tile},
+    text::{Align, TextStyle},
+    transition::{Transition, TransitionKind},
 };
 
 pub enum MenuResult {
     Start,
+    Gallery,
     Quit,
 }
 
 pub fn menu<C: Ctx>(ctx: &mut C) -> Result<MenuResult, C::Error> {
     ctx.set_music(Music::Lemonhead)?;
+    *ctx.camera_mut() = Camera::new();
+    let mut transition: Option<Transition> = None;
+    let mut was_right = ctx.key_down(Key::Right);
     loop {
         ctx.setup()?;
-        if ctx.key_down(crate::ctx::Key::Quit) {
+        let right = ctx.key_down(Key::Right);
+        if ctx.key_down(Key::Quit) {
             break Ok(MenuResult::Quit);
-        } else if ctx.key_down(crate::ctx::Key::Interact) {
-            break Ok(MenuResult::Start);
+        } else if right && !was_right && transition.is_none() {
+            break Ok(MenuResult::Gallery);
+        } else if ctx.key_down(Key::Interact) && transition.is_none() {
+            transition = Some(Transition::cover(
+                TransitionKind::Fade(Rgb(0, 0, 0)),
+                ctx.seconds_elapsed(),
+            ));
         }
+        was_right = right;
         ctx.draw_background_fill(Rgb(255, 255, 255));
-        ctx.draw_sprite((0.0, 9.0), (10.0, 1.0), &Tile::Ground);
+        ctx.draw_sprite(Layer::Background, (0.0, 9.0), (10.0, 1.0), &Tile::Ground);
 
         let lemon_offset = ctx.seconds_elapsed().sin() * 6.5 + 4.5;
         let dad_offset = -ctx.seconds_elapsed().cos();
@@ -32,10 +47,48 @@ pub fn menu<C: Ctx>(ctx: &mut C) -> Result<MenuResult, C::Error> {
         let use_alt = ctx.seconds_elapsed() % 0.2 > 0.10;
         let lemonhead = Actor::lemonhead_sprite(&state, use_alt);
         let dad = Actor::npc_sprite(&state, use_alt, Actor::Dad);
-        ctx.draw_sprite((lemon_offset, 8.0), (1.0, 1.0), &lemonhead);
-        ctx.draw_sprite((lemon_offset + dad_offset, 8.0), (1.0, 1.0), &dad);
+        ctx.draw_sprite(Layer::Actors, (lemon_offset, 8.0), (1.0, 1.0), &lemonhead);
+        ctx.draw_sprite(
+            Layer::Actors,
+            (lemon_offset + dad_offset, 8.0),
+            (1.0, 1.0),
+            &dad,
+        );
         let offset = (ctx.seconds_elapsed() * PI * 2.0).sin() * 0.125;
-        ctx.draw_sprite((1.0, 1.0 + offset), (8.0, 8.0), &Tile::Logo);
+        let style = TextStyle {
+            pixel_size: 1.0 / 8.0,
+            align: Align::Center,
+            ..TextStyle::small(Rgb(0, 0, 0))
+        };
+        let language = ctx.language();
+        ctx.draw_text(
+            Layer::Ui,
+            (5.0, 1.0 + offset),
+            language.text(Message::MenuTitle),
+            &style,
+        );
+        ctx.draw_sprite(Layer::Ui, (1.0, 3.75 + offset), (8.0, 3.0), &Logo);
+        ctx.draw_text(
+            Layer::Ui,
+            (5.0, 7.375 + offset),
+            language.text(Message::MenuStart),
+            &style,
+        );
+        ctx.draw_text(
+            Layer::Ui,
+            (9.75, 0.25),
+            language.text(Message::MenuGallery),
+            &TextStyle {
+                align: Align::Right,
+                ..TextStyle::small(Rgb(110, 110, 110))
+            },
+        );
+        if let Some(ref transition) = transition {
+            transition.draw(ctx, (5.0, 5.0));
+            if transition.finished(ctx.seconds_elapsed()) {
+                break Ok(MenuResult::Start);
+            }
+        }
         ctx.finish()?;
     }
 }
@@ -0,0 +1,31 @@
+pub const GRAVITY: f64 = 30.0;
+pub const JUMP_SPEED: f64 = 8.0;
+const FOOT_WIDTH: f64 = 0.5;
+
+pub struct Collider {
+    pub x: f64,
+    pub width: f64,
+    pub height: f64,
+    pub solid: bool,
+}
+
+impl Collider {
+    fn overlaps(&self, x: f64) -> bool {
+        let (left, right) = (x + 0.5 - FOOT_WIDTH / 2.0, x + 0.5 + FOOT_WIDTH / 2.0);
+        right > self.x && left < self.x + self.width
+    }
+}
+
+pub fn floor(colliders: &[Collider], x: f64, height: f64) -> f64 {
+    colliders
+        .iter()
+        .filter(|collider| collider.height <= height && collider.overlaps(x))
+        .map(|collider| collider.height)
+        .fold(0.0, f64::max)
+}
+
+pub fn blocked(colliders: &[Collider], x: f64, height: f64) -> bool {
+    colliders
+        .iter()
+        .any(|collider| collider.solid && collider.height > height && collider.overlaps(x))
+}
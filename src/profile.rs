@@ -0,0 +1,76 @@
+use crate::{game::GameResult, storage};
+
+const PROFILE_FILE: &str = "profile.cfg";
+
+pub struct Profile {
+    pub escaped: bool,
+    pub dead: bool,
+    pub ascended: bool,
+    pub first_completion: Option<f64>,
+    pub playthroughs: u32,
+}
+
+impl Profile {
+    pub fn load() -> Self {
+        let mut profile = Self {
+            escaped: false,
+            dead: false,
+            ascended: false,
+            first_completion: None,
+            playthroughs: 0,
+        };
+        for (key, value) in storage::read_pairs(PROFILE_FILE) {
+            match key.as_str() {
+                "escaped" => profile.escaped = value == "true",
+                "dead" => profile.dead = value == "true",
+                "ascended" => profile.ascended = value == "true",
+                "first_completion" => profile.first_completion = value.parse().ok(),
+                "playthroughs" => {
+                    if let Ok(playthroughs) = value.parse() {
+                        profile.playthroughs = playthroughs;
+                    }
+                }
+                _ => {}
+            }
+        }
+        profile
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut pairs = vec![
+            ("escaped", self.escaped.to_string()),
+            ("dead", self.dead.to_string()),
+            ("ascended", self.ascended.to_string()),
+            ("playthroughs", self.playthroughs.to_string()),
+        ];
+        if let Some(seconds) = self.first_completion {
+            pairs.push(("first_completion", seconds.to_string()));
+        }
+        storage::write_pairs(PROFILE_FILE, &pairs)
+    }
+
+    pub fn unlocked(&self, result: GameResult) -> bool {
+        match result {
+            GameResult::GoodEnding => self.escaped,
+            GameResult::Dead => self.dead,
+            GameResult::Ascended => self.ascended,
+            GameResult::Quit => false,
+        }
+    }
+
+    pub fn record(&mut self, result: GameResult, seconds: f64) {
+        match result {
+            GameResult::GoodEnding => self.escaped = true,
+            GameResult::Dead => self.dead = true,
+            GameResult::Ascended => self.ascended = true,
+            GameResult::Quit => return,
+        }
+        if matches!(result, GameResult::Dead) {
+            return;
+        }
+        self.playthroughs += 1;
+        if self.first_completion.is_none() {
+            self.first_completion = Some(seconds);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::path::Path;
+
+use crate::ctx::Key;
+
+const KEYS: [(Key, char); 11] = [
+    (Key::Left, 'L'),
+    (Key::Right, 'R'),
+    (Key::Interact, 'I'),
+    (Key::Jump, 'J'),
+    (Key::Debug, 'G'),
+    (Key::Hud, 'H'),
+    (Key::Inventory, 'V'),
+    (Key::Console, 'C'),
+    (Key::Backspace, 'B'),
+    (Key::Submit, 'S'),
+    (Key::Quit, 'Q'),
+];
+
+pub struct Frame {
+    pub time: f64,
+    pub keys: Vec<Key>,
+    pub text: String,
+}
+
+pub struct Recording {
+    pub seed: u64,
+    pub frames: Vec<Frame>,
+}
+
+impl Frame {
+    fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.splitn(3, '\t');
+        let time = parts.next()?.parse().ok()?;
+        let keys = parts
+            .next()?
+            .chars()
+            .map(|code| {
+                KEYS.iter()
+                    .find(|(_, other)| *other == code)
+                    .map(|(key, _)| *key)
+            })
+            .collect::<Option<_>>()?;
+        let text = parts.next().unwrap_or_default().to_owned();
+        Some(Self { time, keys, text })
+    }
+
+    fn format(&self) -> String {
+        let keys: String = KEYS
+            .iter()
+            .filter(|(key, _)| self.keys.contains(key))
+            .map(|(_, code)| code)
+            .collect();
+        format!("{}\t{keys}\t{}\n", self.time, self.text)
+    }
+}
+
+impl Recording {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("unable to read {}: {e}", path.display()))?;
+        let mut lines = content.lines();
+        let seed = lines
+            .next()
+            .and_then(|line| line.strip_prefix("seed="))
+            .and_then(|seed| seed.parse().ok())
+            .ok_or(format!("{} is not a recording", path.display()))?;
+        let frames = lines
+            .enumerate()
+            .map(|(index, line)| {
+                Frame::parse(line).ok_or(format!(
+                    "invalid frame on line {} of {}",
+                    index + 2,
+                    path.display()
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { seed, frames })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut content = format!("seed={}\n", self.seed);
+        content.extend(self.frames.iter().map(Frame::format));
+        std::fs::write(path, content).map_err(|e| e.to_string())
+    }
+}
@@ -6,59 +6,193 @@ mod murder_living_room;
 mod outside;
 mod tutorial;
 
-use std::marker::PhantomData;
+use crate::{
+    camera::VIEW_WIDTH,
+    ctx::Ctx,
+    game::GameResult,
+    inventory::{Inventory, Pickup},
+    physics::Collider,
+    state::State,
+};
 
-use crate::{ctx::Ctx, state::State};
+pub const INTERACT_DISTANCE: f64 = 0.5;
 
-pub struct InteractableId(pub u8);
+pub struct Items<I>(Vec<(f64, f64, I)>);
 
-pub trait Item {
-    fn id(&self) -> InteractableId;
-}
-
-pub struct Items(Vec<(f64, Box<dyn Item>)>);
-
-impl Items {
+impl<I> Items<I> {
     pub fn new() -> Self {
         Self(Vec::new())
     }
 
-    pub fn push(&mut self, position: f64, item: impl Item + 'static) {
-        self.0.push((position, Box::new(item)));
+    pub fn push(&mut self, position: f64, item: I) {
+        self.push_raised(position, 0.0, item);
+    }
+
+    pub fn push_raised(&mut self, position: f64, height: f64, item: I) {
+        self.0.push((position, height, item));
+    }
+
+    pub fn push_pickups(
+        &mut self,
+        inventory: &Inventory,
+        pickups: &[(f64, Pickup)],
+        item: impl Fn(Pickup) -> I,
+    ) {
+        for (position, pickup) in pickups {
+            if !inventory.picked_up(*pickup) {
+                self.push(*position, item(*pickup));
+            }
+        }
+    }
+
+    pub fn positions(&self) -> Vec<(f64, f64)> {
+        self.0
+            .iter()
+            .map(|(position, height, _)| (*position, *height))
+            .collect()
     }
 }
 
-pub trait Scene<C: Ctx> {
-    fn draw(&self, ctx: &mut C, state: &State<C>);
-    fn interact(
-        &self,
-        ctx: &mut C,
-        state: &mut State<C>,
-        item: Box<dyn Item>,
-    ) -> Result<(), C::Error>;
-    fn prepare_items(&self, state: &State<C>) -> Items;
+pub trait Room {
+    type Item;
+
+    fn prepare_items(&self, state: &State) -> Items<Self::Item>;
+    fn width(&self) -> f64 {
+        VIEW_WIDTH
+    }
+    fn bounds(&self) -> (f64, f64) {
+        (0.0, self.width() - 1.0)
+    }
+    fn colliders(&self, _state: &State) -> Vec<Collider> {
+        Vec::new()
+    }
     fn closest_item_within_distance(
         &self,
-        state: &State<C>,
+        state: &State,
         position: f64,
-    ) -> Option<Box<dyn Item>> {
-        let items = self.prepare_items(state).0;
-        if items.is_empty() {
-            return None;
-        }
-        items
+        height: f64,
+    ) -> Option<Self::Item> {
+        self.prepare_items(state)
+            .0
             .into_iter()
-            .map(|(dist, item)| ((dist - position).abs(), item))
-            .filter(|(dist, _)| *dist < 0.5)
+            .filter(|(_, item_height, _)| (item_height - height).abs() < INTERACT_DISTANCE)
+            .map(|(dist, _, item)| ((dist - position).abs(), item))
+            .filter(|(dist, _)| *dist < INTERACT_DISTANCE)
             .min_by(|a, b| (a.0).total_cmp(&b.0))
             .map(|(_dist, item)| item)
     }
-    fn should_draw_interact_popup(&self, state: &State<C>, position: f64) -> bool {
-        self.closest_item_within_distance(state, position).is_some()
+}
+
+pub trait Scene<C: Ctx>: Room {
+    fn draw(&self, ctx: &mut C, state: &State);
+    fn interact(&self, ctx: &mut C, state: &mut State, item: Self::Item) -> Result<(), C::Error>;
+    fn on_enter(&self, _ctx: &mut C, _state: &mut State) -> Result<(), C::Error> {
+        Ok(())
+    }
+    fn on_exit(&self, _ctx: &mut C, _state: &mut State) -> Result<(), C::Error> {
+        Ok(())
+    }
+    fn update(
+        &self,
+        _ctx: &mut C,
+        _state: &mut State,
+        _position: f64,
+        _delta_time: f64,
+    ) -> Result<Option<GameResult>, C::Error> {
+        Ok(None)
+    }
+}
+
+pub trait AnyRoom {
+    fn width(&self) -> f64;
+    fn bounds(&self) -> (f64, f64);
+    fn colliders(&self, state: &State) -> Vec<Collider>;
+    fn should_draw_interact_popup(&self, state: &State, position: f64, height: f64) -> bool;
+    fn item_positions(&self, state: &State) -> Vec<(f64, f64)>;
+}
+
+impl<R: Room> AnyRoom for R {
+    fn width(&self) -> f64 {
+        Room::width(self)
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        Room::bounds(self)
+    }
+
+    fn colliders(&self, state: &State) -> Vec<Collider> {
+        Room::colliders(self, state)
+    }
+
+    fn should_draw_interact_popup(&self, state: &State, position: f64, height: f64) -> bool {
+        self.closest_item_within_distance(state, position, height)
+            .is_some()
+    }
+
+    fn item_positions(&self, state: &State) -> Vec<(f64, f64)> {
+        self.prepare_items(state).positions()
+    }
+}
+
+pub trait AnyScene<C: Ctx> {
+    fn draw(&self, ctx: &mut C, state: &State);
+    fn on_enter(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error>;
+    fn on_exit(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error>;
+    fn update(
+        &self,
+        ctx: &mut C,
+        state: &mut State,
+        position: f64,
+        delta_time: f64,
+    ) -> Result<Option<GameResult>, C::Error>;
+    fn interact_closest(
+        &self,
+        ctx: &mut C,
+        state: &mut State,
+        position: f64,
+        height: f64,
+    ) -> Result<(), C::Error>;
+}
+
+impl<C: Ctx, S: Scene<C>> AnyScene<C> for S {
+    fn draw(&self, ctx: &mut C, state: &State) {
+        Scene::draw(self, ctx, state)
+    }
+
+    fn on_enter(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        Scene::on_enter(self, ctx, state)
+    }
+
+    fn on_exit(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        Scene::on_exit(self, ctx, state)
+    }
+
+    fn update(
+        &self,
+        ctx: &mut C,
+        state: &mut State,
+        position: f64,
+        delta_time: f64,
+    ) -> Result<Option<GameResult>, C::Error> {
+        Scene::update(self, ctx, state, position, delta_time)
+    }
+
+    fn interact_closest(
+        &self,
+        ctx: &mut C,
+        state: &mut State,
+        position: f64,
+        height: f64,
+    ) -> Result<(), C::Error> {
+        match self.closest_item_within_distance(state, position, height) {
+            Some(item) => self.interact(ctx, state, item),
+            None => Ok(()),
+        }
     }
 }
 
-pub enum Scenes<C: Ctx> {
+#[derive(Clone)]
+pub enum Scenes {
     Tutorial,
     Entryway,
     LivingRoom,
@@ -66,11 +200,10 @@ pub enum Scenes<C: Ctx> {
     Outside,
     Kitchen,
     ChildRoom,
-    _Phantom(PhantomData<C>),
 }
 
-impl<C: Ctx> Scenes<C> {
-    fn inner(&self) -> &dyn Scene<C> {
+impl Scenes {
+    fn room(&self) -> &dyn AnyRoom {
         match self {
             Self::Tutorial => &tutorial::Tutorial,
             Self::Entryway => &entryway::Entryway,
@@ -79,25 +212,97 @@ impl<C: Ctx> Scenes<C> {
             Self::Outside => &outside::Outside,
             Self::Kitchen => &kitchen::Kitchen,
             Self::ChildRoom => &child_room::ChildRoom,
-            Self::_Phantom(_) => unreachable!(),
         }
     }
-}
-impl<C: Ctx> Scene<C> for Scenes<C> {
-    fn draw(&self, ctx: &mut C, state: &State<C>) {
+
+    fn inner<C: Ctx>(&self) -> &dyn AnyScene<C> {
+        match self {
+            Self::Tutorial => &tutorial::Tutorial,
+            Self::Entryway => &entryway::Entryway,
+            Self::LivingRoom => &living_room::LivingRoom,
+            Self::MurderLivingRoom => &murder_living_room::MurderLivingRoom,
+            Self::Outside => &outside::Outside,
+            Self::Kitchen => &kitchen::Kitchen,
+            Self::ChildRoom => &child_room::ChildRoom,
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            Self::Tutorial,
+            Self::Entryway,
+            Self::LivingRoom,
+            Self::MurderLivingRoom,
+            Self::Outside,
+            Self::Kitchen,
+            Self::ChildRoom,
+        ]
+        .into_iter()
+        .find(|scene| scene.name() == value)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Tutorial => "tutorial",
+            Self::Entryway => "entryway",
+            Self::LivingRoom => "living_room",
+            Self::MurderLivingRoom => "murder_living_room",
+            Self::Outside => "outside",
+            Self::Kitchen => "kitchen",
+            Self::ChildRoom => "child_room",
+        }
+    }
+
+    pub fn draw<C: Ctx>(&self, ctx: &mut C, state: &State) {
         self.inner().draw(ctx, state)
     }
 
-    fn interact(
+    pub fn width(&self) -> f64 {
+        self.room().width()
+    }
+
+    pub fn bounds(&self) -> (f64, f64) {
+        self.room().bounds()
+    }
+
+    pub fn colliders(&self, state: &State) -> Vec<Collider> {
+        self.room().colliders(state)
+    }
+
+    pub fn on_enter<C: Ctx>(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        self.inner().on_enter(ctx, state)
+    }
+
+    pub fn on_exit<C: Ctx>(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        self.inner().on_exit(ctx, state)
+    }
+
+    pub fn update<C: Ctx>(
+        &self,
+        ctx: &mut C,
+        state: &mut State,
+        position: f64,
+        delta_time: f64,
+    ) -> Result<Option<GameResult>, C::Error> {
+        self.inner().update(ctx, state, position, delta_time)
+    }
+
+    pub fn interact_closest<C: Ctx>(
         &self,
         ctx: &mut C,
-        state: &mut State<C>,
-        item: Box<dyn Item>,
-    ) -> Result<(), <C as Ctx>::Error> {
-        self.inner().interact(ctx, state, item)
+        state: &mut State,
+        position: f64,
+        height: f64,
+    ) -> Result<(), C::Error> {
+        self.inner().interact_closest(ctx, state, position, height)
+    }
+
+    pub fn should_draw_interact_popup(&self, state: &State, position: f64, height: f64) -> bool {
+        self.room()
+            .should_draw_interact_popup(state, position, height)
     }
 
-    fn prepare_items(&self, state: &State<C>) -> Items {
-        self.inner().prepare_items(state)
+    pub fn item_positions(&self, state: &State) -> Vec<(f64, f64)> {
+        self.room().item_positions(state)
     }
 }
@@ -1,82 +1,120 @@
-use crate::ctx::{Ctx, Effect};
+use crate::ctx::{Ctx, Effect, Layer, Music};
+use crate::dialogue::{self, Dialogue};
 use crate::globals::GROUND_LEVEL;
 use crate::helper::CtxHelperExt;
-use crate::sprite::{Actor, Blood, Npc, Tile};
+use crate::physics::Collider;
+use crate::sprite::{Actor, ActorState, Blood, Npc, Tile};
 use crate::state::State;
 
-use super::{InteractableId, Item, Items, Scene};
+use super::{Items, Room, Scene};
 
 use super::Scenes;
 
 #[derive(Default)]
 pub struct ChildRoom;
 
-enum Interactables {
+const BED: Collider = Collider {
+    x: 6.0,
+    width: 1.0,
+    height: 0.5,
+    solid: true,
+};
+const CHILD: Collider = Collider {
+    x: 5.375,
+    width: 0.25,
+    height: 2.0,
+    solid: true,
+};
+
+pub enum Interactables {
     ExitDoor,
     Child,
 }
 
-impl Item for Interactables {
-    fn id(&self) -> InteractableId {
-        match self {
-            Interactables::ExitDoor => InteractableId(0),
-            Interactables::Child => InteractableId(1),
-        }
-    }
-}
-
-impl From<InteractableId> for Interactables {
-    fn from(value: InteractableId) -> Self {
-        match value {
-            InteractableId(0) => Self::ExitDoor,
-            InteractableId(1) => Self::Child,
-            InteractableId(_) => unreachable!(),
-        }
-    }
-}
-
 impl ChildRoom {
     fn draw_house<C: Ctx>(&self, ctx: &mut C) {
         ctx.draw_ground();
         ctx.draw_wallpaper(&Tile::DotWallpaper);
-        ctx.draw_sprite((1.0, GROUND_LEVEL), (1.0, 1.0), &Tile::DoorOpen);
+        ctx.draw_sprite(
+            Layer::Props,
+            (1.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::DoorOpen,
+        );
 
-        ctx.draw_sprite((3.0, GROUND_LEVEL), (1.0, 1.0), &Tile::ChildPoster);
-        ctx.draw_sprite((4.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Computer);
-        ctx.draw_sprite((4.0, GROUND_LEVEL), (1.0, 1.0), &Tile::OfficeChair);
-        ctx.draw_sprite((6.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Bed);
+        ctx.draw_sprite(
+            Layer::Props,
+            (3.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::ChildPoster,
+        );
+        ctx.draw_sprite(
+            Layer::Props,
+            (4.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::Computer,
+        );
+        ctx.draw_sprite(
+            Layer::Props,
+            (4.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::OfficeChair,
+        );
+        ctx.draw_sprite(Layer::Props, (6.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Bed);
     }
 
-    fn draw_child<C: Ctx>(&self, ctx: &mut C, state: &State<C>) {
+    fn draw_child<C: Ctx>(&self, ctx: &mut C, state: &State) {
         let child = if state.child_room.child_dead() {
             Actor::Child(Npc::Dead)
+        } else if state.child_room.playing_hide_and_seek {
+            Actor::npc_sprite(&ActorState::Left, false, Actor::Child)
         } else if ctx.seconds_elapsed() % 1.0 < 0.5 {
             Actor::Child(Npc::Idle)
         } else {
             Actor::Child(Npc::IdleAlt)
         };
 
-        ctx.draw_sprite((5.0, GROUND_LEVEL), (1.0, 1.0), &child);
+        ctx.draw_sprite(Layer::Actors, (5.0, GROUND_LEVEL), (1.0, 1.0), &child);
 
         if state.child_room.child_stabs > 0 {
-            ctx.draw_sprite((5.0, GROUND_LEVEL), (1.0, 1.0), &Blood::SplatterCenter);
+            ctx.draw_sprite(
+                Layer::Decals,
+                (5.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Blood::SplatterCenter,
+            );
         }
         if state.child_room.child_stabs > 1 {
-            ctx.draw_sprite((4.0, GROUND_LEVEL), (1.0, 1.0), &Blood::SplatterRight);
+            ctx.draw_sprite(
+                Layer::Decals,
+                (4.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Blood::SplatterRight,
+            );
         }
         if state.child_room.child_stabs > 2 {
-            ctx.draw_sprite((6.0, GROUND_LEVEL), (1.0, 1.0), &Blood::SplatterLeft);
+            ctx.draw_sprite(
+                Layer::Decals,
+                (6.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Blood::SplatterLeft,
+            );
         }
     }
 }
 
-impl<C: Ctx> Scene<C> for ChildRoom {
-    fn draw(&self, ctx: &mut C, state: &crate::state::State<C>) {
-        self.draw_house(ctx);
-        self.draw_child(ctx, state);
+impl Room for ChildRoom {
+    type Item = Interactables;
+
+    fn colliders(&self, state: &State) -> Vec<Collider> {
+        let mut colliders = vec![BED];
+        if !state.child_room.child_dead() {
+            colliders.push(CHILD);
+        }
+        colliders
     }
 
-    fn prepare_items(&self, state: &State<C>) -> Items {
+    fn prepare_items(&self, state: &State) -> Items<Interactables> {
         let mut items = Items::new();
         if state.child_room.child_stabs < 3 {
             items.push(5.0, Interactables::Child);
@@ -86,14 +124,25 @@ impl<C: Ctx> Scene<C> for ChildRoom {
         }
         items
     }
+}
+
+impl<C: Ctx> Scene<C> for ChildRoom {
+    fn draw(&self, ctx: &mut C, state: &crate::state::State) {
+        self.draw_house(ctx);
+        self.draw_child(ctx, state);
+    }
+
+    fn on_enter(&self, ctx: &mut C, _state: &mut State) -> Result<(), C::Error> {
+        ctx.set_music(Music::HeartbeatChildWithLemon)
+    }
 
     fn interact(
         &self,
         ctx: &mut C,
-        state: &mut State<C>,
-        item: Box<dyn Item>,
+        state: &mut State,
+        item: Interactables,
     ) -> Result<(), C::Error> {
-        match item.id().into() {
+        match item {
             Interactables::ExitDoor => {
                 ctx.play_effect(Effect::Interact)?;
                 if state.child_room.child_stabs < 3 {
@@ -101,13 +150,22 @@ impl<C: Ctx> Scene<C> for ChildRoom {
                 }
                 state.scene_changed = Some((4.0, Scenes::Entryway));
             }
+            Interactables::Child if !state.child_room.greeted => {
+                ctx.play_effect(Effect::Interact)?;
+                state.child_room.greeted = true;
+                state.dialogue = Some(Dialogue::new(
+                    dialogue::CHILD_GREETING,
+                    5.0,
+                    ctx.seconds_elapsed(),
+                ));
+            }
             Interactables::Child => {
                 ctx.play_effect(Effect::Stab)?;
                 state.child_room.child_stabs += 1;
                 if state.child_room.child_stabs == 1 {
-                    ctx.set_music(crate::ctx::Music::HeartbeatChild)?;
+                    ctx.set_music(Music::HeartbeatChild)?;
                 } else if state.child_room.child_stabs == 2 {
-                    ctx.set_music(crate::ctx::Music::Heartbeat)?;
+                    ctx.set_music(Music::Heartbeat)?;
                 } else if state.child_room.child_stabs == 3 {
                     ctx.stop_music()?;
                 } else {
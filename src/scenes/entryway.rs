@@ -1,7 +1,8 @@
-use super::{InteractableId, Item, Items, Scene};
-use crate::ctx::{Ctx, Effect, Music};
+use super::{Items, Room, Scene};
+use crate::ctx::{Ctx, Effect, Layer, Music};
 use crate::globals::GROUND_LEVEL;
 use crate::helper::CtxHelperExt;
+use crate::inventory::{Pickup, Requirement, ENTRYWAY_COINS};
 use crate::sprite::{Blood, Tile};
 use crate::state::State;
 
@@ -10,51 +11,33 @@ use super::Scenes;
 #[derive(Default)]
 pub struct Entryway;
 
-enum Interactables {
+pub enum Interactables {
     KitchenDoor,
     ExitDoor,
     ChildDoor,
-    Coin0,
-    Coin1,
-    Coin2,
-    Coin3,
+    Pickup(Pickup),
 }
 
-impl Item for Interactables {
-    fn id(&self) -> InteractableId {
-        match self {
-            Interactables::KitchenDoor => InteractableId(0),
-            Interactables::ExitDoor => InteractableId(1),
-            Interactables::ChildDoor => InteractableId(2),
-            Interactables::Coin0 => InteractableId(3),
-            Interactables::Coin1 => InteractableId(4),
-            Interactables::Coin2 => InteractableId(5),
-            Interactables::Coin3 => InteractableId(6),
-        }
-    }
-}
-
-impl From<InteractableId> for Interactables {
-    fn from(value: InteractableId) -> Self {
-        match value {
-            InteractableId(0) => Self::KitchenDoor,
-            InteractableId(1) => Self::ExitDoor,
-            InteractableId(2) => Self::ChildDoor,
-            InteractableId(3) => Self::Coin0,
-            InteractableId(4) => Self::Coin1,
-            InteractableId(5) => Self::Coin2,
-            InteractableId(6) => Self::Coin3,
-            InteractableId(_) => unreachable!(),
-        }
-    }
-}
+const COINS: [(f64, Pickup); 4] = [
+    (3.0, Pickup::EntrywayCoin(0)),
+    (4.0, Pickup::EntrywayCoin(1)),
+    (5.0, Pickup::EntrywayCoin(2)),
+    (6.0, Pickup::EntrywayCoin(3)),
+];
+const BONUS_COIN: (f64, f64, Pickup) = (7.0, 1.0, Pickup::BonusCoin(0));
+const KITCHEN_DOOR: Requirement = Requirement::AllOf(ENTRYWAY_COINS);
 
 impl Entryway {
-    fn draw_house<C: Ctx>(&self, ctx: &mut C, state: &State<C>) {
+    fn draw_house<C: Ctx>(&self, ctx: &mut C, state: &State) {
         ctx.draw_ground();
         ctx.draw_wallpaper(&Tile::StripeWallpaper);
 
-        ctx.draw_sprite((1.0, GROUND_LEVEL), (1.0, 1.0), &Tile::DoorOpen);
+        ctx.draw_sprite(
+            Layer::Props,
+            (1.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::DoorOpen,
+        );
 
         let picture_tile = if state.child_room.child_stabs > 0 {
             Tile::LemonDayPicture
@@ -62,21 +45,31 @@ impl Entryway {
             Tile::TreeDayPicture
         };
 
-        ctx.draw_sprite((7.0, GROUND_LEVEL), (1.0, 1.0), &picture_tile);
+        ctx.draw_sprite(Layer::Props, (7.0, GROUND_LEVEL), (1.0, 1.0), &picture_tile);
 
-        ctx.draw_sprite((2.0, GROUND_LEVEL), (1.0, 1.0), &Tile::HousePicture);
+        ctx.draw_sprite(
+            Layer::Props,
+            (2.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::HousePicture,
+        );
 
         if state.murder_living_room.dad_dead {
-            ctx.draw_sprite((2.0, GROUND_LEVEL), (1.0, 1.0), &Blood::SplatterCenter);
+            ctx.draw_sprite(
+                Layer::Decals,
+                (2.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Blood::SplatterCenter,
+            );
         }
 
-        let kitchen_door = if state.entryway.all_coins_collected() {
+        let kitchen_door = if state.meets(&KITCHEN_DOOR) {
             Tile::DoorOpen
         } else {
             Tile::DoorClosed
         };
 
-        ctx.draw_sprite((8.0, GROUND_LEVEL), (1.0, 1.0), &kitchen_door);
+        ctx.draw_sprite(Layer::Props, (8.0, GROUND_LEVEL), (1.0, 1.0), &kitchen_door);
 
         let child_door = if state.murder_living_room.dad_dead {
             Tile::DoorOpen
@@ -84,52 +77,46 @@ impl Entryway {
             Tile::DoorClosed
         };
 
-        ctx.draw_sprite((4.0, GROUND_LEVEL), (1.0, 1.0), &child_door);
+        ctx.draw_sprite(Layer::Props, (4.0, GROUND_LEVEL), (1.0, 1.0), &child_door);
 
         if !state.murder_living_room.dad_dead {
-            ctx.draw_sprite((4.0, GROUND_LEVEL), (1.0, 1.0), &Tile::ChildSticker);
+            ctx.draw_sprite(
+                Layer::Props,
+                (4.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Tile::ChildSticker,
+            );
         }
 
         if state.murder_living_room.dad_dead && state.child_room.child_stabs == 0 {
-            ctx.draw_sprite((4.0, GROUND_LEVEL - 1.0), (1.0, 1.0), &Blood::Pentagram);
+            ctx.draw_sprite(
+                Layer::Decals,
+                (4.0, GROUND_LEVEL - 1.0),
+                (1.0, 1.0),
+                &Blood::Pentagram,
+            );
         }
 
-        if !state.entryway.coins[0] {
-            ctx.draw_item(&Tile::Coin, 3.0);
-        }
-        if !state.entryway.coins[1] {
-            ctx.draw_item(&Tile::Coin, 4.0);
-        }
-        if !state.entryway.coins[2] {
-            ctx.draw_item(&Tile::Coin, 5.0);
-        }
-        if !state.entryway.coins[3] {
-            ctx.draw_item(&Tile::Coin, 6.0);
+        ctx.draw_pickups(&state.inventory, &COINS);
+        let (x, height, pickup) = BONUS_COIN;
+        if !state.inventory.picked_up(pickup) {
+            ctx.draw_raised_item(&Tile::Coin, x, height);
         }
     }
 }
 
-impl<C: Ctx> Scene<C> for Entryway {
-    fn draw(&self, ctx: &mut C, state: &State<C>) {
-        self.draw_house(ctx, state);
-    }
+impl Room for Entryway {
+    type Item = Interactables;
 
-    fn prepare_items(&self, state: &State<C>) -> Items {
+    fn prepare_items(&self, state: &State) -> Items<Interactables> {
         let mut items = Items::new();
         items.push(1.0, Interactables::ExitDoor);
-        if !state.entryway.coins[0] {
-            items.push(3.0, Interactables::Coin0);
+        items.push_pickups(&state.inventory, &COINS, Interactables::Pickup);
+        let (x, height, pickup) = BONUS_COIN;
+        if !state.inventory.picked_up(pickup) {
+            items.push_raised(x, height, Interactables::Pickup(pickup));
         }
-        if !state.entryway.coins[1] {
-            items.push(4.0, Interactables::Coin1);
-        }
-        if !state.entryway.coins[2] {
-            items.push(5.0, Interactables::Coin2);
-        }
-        if !state.entryway.coins[3] {
-            items.push(6.0, Interactables::Coin3);
-        }
-        if state.entryway.all_coins_collected() {
+        if state.meets(&KITCHEN_DOOR) {
             items.push(8.0, Interactables::KitchenDoor);
         }
         if state.murder_living_room.dad_dead {
@@ -137,30 +124,36 @@ impl<C: Ctx> Scene<C> for Entryway {
         }
         items
     }
+}
+
+impl<C: Ctx> Scene<C> for Entryway {
+    fn draw(&self, ctx: &mut C, state: &State) {
+        self.draw_house(ctx, state);
+    }
+
+    fn on_enter(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        if !state.living_room.has_escaped_dad {
+            ctx.set_music(Music::Lemonhead)?;
+        }
+        Ok(())
+    }
 
     fn interact(
         &self,
         ctx: &mut C,
-        state: &mut State<C>,
-        item: Box<dyn Item>,
+        state: &mut State,
+        item: Interactables,
     ) -> Result<(), C::Error> {
         ctx.play_effect(Effect::Interact)?;
-        match item.id().into() {
+        match item {
             Interactables::ExitDoor => {
                 if state.murder_living_room.dad_dead && !state.child_room.child_dead() {
                     return Ok(());
                 }
                 state.scene_changed = Some((7.0, Scenes::Outside));
-                if !state.child_room.child_dead() {
-                    ctx.set_music(Music::Outside)?;
-                }
             }
-            Interactables::Coin0 => state.entryway.coins[0] = true,
-            Interactables::Coin1 => state.entryway.coins[1] = true,
-            Interactables::Coin2 => state.entryway.coins[2] = true,
-            Interactables::Coin3 => state.entryway.coins[3] = true,
+            Interactables::Pickup(pickup) => state.pick_up(pickup),
             Interactables::ChildDoor => {
-                ctx.set_music(Music::HeartbeatChildWithLemon)?;
                 state.scene_changed = Some((1.0, Scenes::ChildRoom));
             }
             Interactables::KitchenDoor => {
@@ -1,6 +1,7 @@
-use super::{InteractableId, Item, Items, Scene};
-use crate::ctx::{Effect, Music};
+use super::{Items, Room, Scene};
+use crate::ctx::{Effect, Layer, Music};
 use crate::helper::CtxHelperExt;
+use crate::inventory::{ItemKind, Pickup, Requirement, KITCHEN_COINS};
 use crate::sprite::Blood;
 use crate::{ctx::Ctx, globals::GROUND_LEVEL, sprite::Tile, state::State};
 
@@ -9,48 +10,33 @@ use super::Scenes;
 #[derive(Default)]
 pub struct Kitchen;
 
-enum Interactables {
+pub enum Interactables {
     ExitDoor,
     LivingRoomDoor,
     Weapon,
-    Coin0,
-    Coin1,
-    Coin2,
+    Pickup(Pickup),
 }
 
-impl Item for Interactables {
-    fn id(&self) -> InteractableId {
-        match self {
-            Interactables::ExitDoor => InteractableId(0),
-            Interactables::LivingRoomDoor => InteractableId(1),
-            Interactables::Weapon => InteractableId(2),
-            Interactables::Coin0 => InteractableId(3),
-            Interactables::Coin1 => InteractableId(4),
-            Interactables::Coin2 => InteractableId(5),
-        }
-    }
-}
-
-impl From<InteractableId> for Interactables {
-    fn from(value: InteractableId) -> Self {
-        match value {
-            InteractableId(0) => Self::ExitDoor,
-            InteractableId(1) => Self::LivingRoomDoor,
-            InteractableId(2) => Self::Weapon,
-            InteractableId(3) => Self::Coin0,
-            InteractableId(4) => Self::Coin1,
-            InteractableId(5) => Self::Coin2,
-            InteractableId(_) => unreachable!(),
-        }
-    }
-}
+const COINS: [(f64, Pickup); 3] = [
+    (3.0, Pickup::KitchenCoin(0)),
+    (4.0, Pickup::KitchenCoin(1)),
+    (5.0, Pickup::KitchenCoin(2)),
+];
+const WEAPON: (f64, Pickup) = (6.0, Pickup::KitchenWeapon);
+const LIVING_ROOM_DOOR: Requirement = Requirement::AllOf(KITCHEN_COINS);
+const ARMED: Requirement = Requirement::Has(ItemKind::Weapon);
 
 impl Kitchen {
-    fn draw_house<C: Ctx>(&self, ctx: &mut C, state: &State<C>) {
+    fn draw_house<C: Ctx>(&self, ctx: &mut C, state: &State) {
         ctx.draw_ground();
         ctx.draw_wallpaper(&Tile::KitchenBrick);
-        ctx.draw_sprite((1.0, GROUND_LEVEL), (1.0, 1.0), &Tile::DoorOpen);
-        ctx.draw_sprite((3.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Oven);
+        ctx.draw_sprite(
+            Layer::Props,
+            (1.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::DoorOpen,
+        );
+        ctx.draw_sprite(Layer::Props, (3.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Oven);
 
         let picture = if state.murder_living_room.dad_dead {
             Tile::LemonNightPicture
@@ -58,94 +44,111 @@ impl Kitchen {
             Tile::TreeNightPicture
         };
 
-        ctx.draw_sprite((9.0, GROUND_LEVEL), (1.0, 1.0), &picture);
+        ctx.draw_sprite(Layer::Props, (9.0, GROUND_LEVEL), (1.0, 1.0), &picture);
 
-        let living_room_door = if state.kitchen.all_coins_collected() {
+        let living_room_door = if state.meets(&LIVING_ROOM_DOOR) {
             Tile::DoorOpen
         } else {
             Tile::DoorClosed
         };
 
-        ctx.draw_sprite((8.0, GROUND_LEVEL), (1.0, 1.0), &living_room_door);
+        ctx.draw_sprite(
+            Layer::Props,
+            (8.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &living_room_door,
+        );
 
         if state.murder_living_room.dad_dead {
-            ctx.draw_sprite((3.0, GROUND_LEVEL), (1.0, 1.0), &Blood::SplatterRight);
-            ctx.draw_sprite((4.0, GROUND_LEVEL), (1.0, 1.0), &Blood::SplatterLeft);
-
-            ctx.draw_sprite((6.0, GROUND_LEVEL - 1.0), (1.0, 1.0), &Blood::PraiseLemon);
+            ctx.draw_sprite(
+                Layer::Decals,
+                (3.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Blood::SplatterRight,
+            );
+            ctx.draw_sprite(
+                Layer::Decals,
+                (4.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Blood::SplatterLeft,
+            );
+
+            ctx.draw_sprite(
+                Layer::Decals,
+                (6.0, GROUND_LEVEL - 1.0),
+                (1.0, 1.0),
+                &Blood::PraiseLemon,
+            );
         }
 
-        if !state.kitchen.coins[0] {
-            ctx.draw_item(&Tile::Coin, 3.0);
-        }
-        if !state.kitchen.coins[1] {
-            ctx.draw_item(&Tile::Coin, 4.0);
-        }
-        if !state.kitchen.coins[2] {
-            ctx.draw_item(&Tile::Coin, 5.0);
-        }
-        if !state.kitchen.weapon_collected {
-            ctx.draw_item(&Tile::Weapon, 6.0);
-        }
+        ctx.draw_pickups(&state.inventory, &COINS);
+        ctx.draw_pickups(&state.inventory, &[WEAPON]);
     }
 }
 
-impl<C: Ctx> Scene<C> for Kitchen {
-    fn draw(&self, ctx: &mut C, state: &State<C>) {
-        self.draw_house(ctx, state);
-    }
+impl Room for Kitchen {
+    type Item = Interactables;
 
-    fn prepare_items(&self, state: &State<C>) -> Items {
+    fn prepare_items(&self, state: &State) -> Items<Interactables> {
         let mut items = Items::new();
 
         items.push(1.0, Interactables::ExitDoor);
 
-        if !state.kitchen.coins[0] {
-            items.push(3.0, Interactables::Coin0);
-        }
-        if !state.kitchen.coins[1] {
-            items.push(4.0, Interactables::Coin1);
-        }
-        if !state.kitchen.coins[2] {
-            items.push(5.0, Interactables::Coin2);
-        }
-        if state.kitchen.all_coins_collected() {
+        items.push_pickups(&state.inventory, &COINS, Interactables::Pickup);
+        if state.meets(&LIVING_ROOM_DOOR) {
             items.push(8.0, Interactables::LivingRoomDoor);
         }
-        if state.living_room.has_escaped_dad && !state.kitchen.weapon_collected {
-            items.push(6.0, Interactables::Weapon);
+        if state.living_room.has_escaped_dad {
+            items.push_pickups(&state.inventory, &[WEAPON], |_| Interactables::Weapon);
         }
 
         items
     }
+}
+
+impl<C: Ctx> Scene<C> for Kitchen {
+    fn draw(&self, ctx: &mut C, state: &State) {
+        self.draw_house(ctx, state);
+    }
+
+    fn on_enter(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        if state.murder_living_room.dad_dead {
+            return Ok(());
+        }
+        let music = if state.meets(&ARMED) {
+            Music::Heartbeat
+        } else if state.living_room.has_escaped_dad {
+            Music::Run
+        } else {
+            Music::Lemonhead
+        };
+        ctx.set_music(music)
+    }
 
     fn interact(
         &self,
         ctx: &mut C,
-        state: &mut State<C>,
-        item: Box<dyn Item>,
+        state: &mut State,
+        item: Interactables,
     ) -> Result<(), C::Error> {
         ctx.play_effect(Effect::Interact)?;
-        match item.id().into() {
+        match item {
             Interactables::ExitDoor => {
-                if state.living_room.has_escaped_dad && !state.kitchen.weapon_collected {
+                if state.living_room.has_escaped_dad && !state.meets(&ARMED) {
                     return Ok(());
                 }
                 state.scene_changed = Some((8.0, Scenes::Entryway));
             }
-            Interactables::Coin0 => state.kitchen.coins[0] = true,
-            Interactables::Coin1 => state.kitchen.coins[1] = true,
-            Interactables::Coin2 => state.kitchen.coins[2] = true,
+            Interactables::Pickup(pickup) => state.pick_up(pickup),
             Interactables::Weapon => {
-                state.kitchen.weapon_collected = true;
+                state.pick_up(WEAPON.1);
                 ctx.set_music(Music::Heartbeat)?;
             }
             Interactables::LivingRoomDoor => {
-                if state.living_room.has_escaped_dad && !state.kitchen.weapon_collected {
+                if state.living_room.has_escaped_dad && !state.meets(&ARMED) {
                     return Ok(());
                 }
-                let scene = if state.kitchen.weapon_collected {
-                    state.murder_living_room.murderous_intent = true;
+                let scene = if state.meets(&ARMED) {
                     Scenes::MurderLivingRoom
                 } else {
                     Scenes::LivingRoom
@@ -1,7 +1,12 @@
-use super::{InteractableId, Item, Items, Scene};
-use crate::ctx::{Ctx, Effect, Music};
+use super::{Items, Room, Scene};
+use crate::ctx::{Ctx, Effect, Layer, Music};
+use crate::cutscene::Cutscene;
+use crate::dialogue::{self, Dialogue};
+use crate::game::GameResult;
 use crate::globals::GROUND_LEVEL;
 use crate::helper::CtxHelperExt;
+use crate::inventory::{Pickup, Requirement, LIVING_ROOM_COINS};
+use crate::physics::Collider;
 use crate::sprite::{Actor, ActorState, Bubble, Tile};
 use crate::state::State;
 
@@ -10,59 +15,64 @@ use super::Scenes;
 #[derive(Default)]
 pub struct LivingRoom;
 
-enum Interactables {
+pub enum Interactables {
     ExitDoor,
-    Coin0,
-    Coin1,
+    Pickup(Pickup),
 }
 
-impl Item for Interactables {
-    fn id(&self) -> InteractableId {
-        match self {
-            Self::ExitDoor => InteractableId(0),
-            Self::Coin0 => InteractableId(1),
-            Self::Coin1 => InteractableId(2),
-        }
-    }
-}
-
-impl From<InteractableId> for Interactables {
-    fn from(value: InteractableId) -> Self {
-        match value {
-            InteractableId(0) => Self::ExitDoor,
-            InteractableId(1) => Self::Coin0,
-            InteractableId(2) => Self::Coin1,
-            InteractableId(_) => unreachable!(),
-        }
-    }
-}
+const COINS: [(f64, Pickup); 2] = [
+    (3.0, Pickup::LivingRoomCoin(0)),
+    (8.0, Pickup::LivingRoomCoin(1)),
+];
+const BONUS_COIN: (f64, f64, Pickup) = (6.0, 1.75, Pickup::BonusCoin(1));
+const COUCH: Collider = Collider {
+    x: 6.0,
+    width: 1.0,
+    height: 0.5,
+    solid: true,
+};
+const CONFRONTATION: Requirement = Requirement::AllOf(LIVING_ROOM_COINS);
 
 impl LivingRoom {
-    fn draw_house<C: Ctx>(&self, ctx: &mut C, state: &State<C>) {
+    fn draw_house<C: Ctx>(&self, ctx: &mut C, state: &State) {
         ctx.draw_ground();
         ctx.draw_wallpaper(&Tile::StripeWallpaper);
 
-        ctx.draw_sprite((1.0, GROUND_LEVEL), (1.0, 1.0), &Tile::DoorOpen);
+        ctx.draw_sprite(
+            Layer::Props,
+            (1.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::DoorOpen,
+        );
 
-        ctx.draw_sprite((3.0, GROUND_LEVEL), (1.0, 1.0), &Tile::TreeDayPicture);
-        ctx.draw_sprite((4.0, GROUND_LEVEL), (1.0, 1.0), &Tile::HousePicture);
-        ctx.draw_sprite((6.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Couch);
+        ctx.draw_sprite(
+            Layer::Props,
+            (3.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::TreeDayPicture,
+        );
+        ctx.draw_sprite(
+            Layer::Props,
+            (4.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::HousePicture,
+        );
+        ctx.draw_sprite(Layer::Props, (6.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Couch);
 
-        if !state.living_room.coins[0] {
-            ctx.draw_item(&Tile::Coin, 3.0);
-        }
-        if !state.living_room.coins[1] {
-            ctx.draw_item(&Tile::Coin, 8.0);
+        ctx.draw_pickups(&state.inventory, &COINS);
+        let (x, height, pickup) = BONUS_COIN;
+        if !state.inventory.picked_up(pickup) {
+            ctx.draw_raised_item(&Tile::Coin, x, height);
         }
     }
 
-    fn draw_confrontation<C: Ctx>(&self, ctx: &mut C, state: &State<C>) {
-        if !state.living_room.all_coins_collected() {
+    fn draw_confrontation<C: Ctx>(&self, ctx: &mut C, state: &State) {
+        if !state.meets(&CONFRONTATION) {
             return;
         }
         let bubble = {
             use Bubble::*;
-            let conf = state.living_room.dad_attack_seconds * 8.0;
+            let conf = state.living_room.dad_walked() * 4.0;
             [L0, L1, L2, L3, L4, L5, L6, L7]
                 .into_iter()
                 .enumerate()
@@ -82,65 +92,102 @@ impl LivingRoom {
             Actor::Dad,
         );
         ctx.draw_sprite(
-            (
-                14.0 - state.living_room.dad_attack_seconds * 2.0,
-                GROUND_LEVEL,
-            ),
+            Layer::Actors,
+            (state.living_room.dad_position, GROUND_LEVEL),
             (1.0, 1.0),
             &dad,
         );
 
         if let Some(bubble) = bubble {
-            ctx.draw_sprite((9.0, GROUND_LEVEL), (1.0, 1.0), &bubble);
+            let position = ctx.camera().to_view((9.0, GROUND_LEVEL));
+            ctx.draw_sprite(Layer::Ui, position, (1.0, 1.0), &bubble);
         }
     }
 }
 
+impl Room for LivingRoom {
+    type Item = Interactables;
+
+    fn colliders(&self, _state: &State) -> Vec<Collider> {
+        vec![COUCH]
+    }
+
+    fn prepare_items(&self, state: &State) -> Items<Interactables> {
+        let mut items = Items::new();
+        items.push_pickups(&state.inventory, &COINS, Interactables::Pickup);
+        let (x, height, pickup) = BONUS_COIN;
+        if !state.inventory.picked_up(pickup) {
+            items.push_raised(x, height, Interactables::Pickup(pickup));
+        }
+        if state.meets(&CONFRONTATION) {
+            items.push(1.0, Interactables::ExitDoor);
+        }
+        items
+    }
+}
+
 impl<C: Ctx> Scene<C> for LivingRoom {
-    fn draw(&self, ctx: &mut C, state: &crate::state::State<C>) {
+    fn draw(&self, ctx: &mut C, state: &crate::state::State) {
         self.draw_house(ctx, state);
         self.draw_confrontation(ctx, state);
     }
 
+    fn on_enter(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        if !state.dad_chasing() {
+            return ctx.set_music(Music::Lemonhead);
+        }
+        state.cutscene = Some(Cutscene::dad_approach(state.living_room.dad_position));
+        ctx.set_music(Music::Run)
+    }
+
+    fn on_exit(&self, _ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        if state.meets(&CONFRONTATION) {
+            state.living_room.has_escaped_dad = true;
+            state.cutscene = None;
+        }
+        Ok(())
+    }
+
+    fn update(
+        &self,
+        _ctx: &mut C,
+        state: &mut State,
+        position: f64,
+        _delta_time: f64,
+    ) -> Result<Option<GameResult>, C::Error> {
+        if !state.meets(&CONFRONTATION) {
+            return Ok(None);
+        }
+        if state.living_room.dad_position - 0.35 <= position {
+            return Ok(Some(GameResult::Dead));
+        }
+        Ok(None)
+    }
+
     fn interact(
         &self,
         ctx: &mut C,
-        state: &mut crate::state::State<C>,
-        item: Box<dyn Item>,
+        state: &mut crate::state::State,
+        item: Interactables,
     ) -> Result<(), C::Error> {
         ctx.play_effect(Effect::Interact)?;
-        match item.id().into() {
+        match item {
             Interactables::ExitDoor => {
-                state.living_room.has_escaped_dad = true;
                 state.scene_changed = Some((8.0, Scenes::Kitchen));
             }
-            Interactables::Coin0 => {
-                state.living_room.coins[0] = true;
-                if state.living_room.coins[1] {
-                    ctx.set_music(Music::Run)?;
-                };
-            }
-            Interactables::Coin1 => {
-                state.living_room.coins[1] = true;
-                if state.living_room.coins[0] {
+            Interactables::Pickup(pickup) => {
+                state.pick_up(pickup);
+                if state.meets(&CONFRONTATION) {
                     ctx.set_music(Music::Run)?;
+                    state.cutscene = Some(Cutscene::dad_approach(state.living_room.dad_position));
+                    state.dialogue = Some(Dialogue::new(
+                        dialogue::DAD_COINS,
+                        9.0,
+                        ctx.seconds_elapsed(),
+                    ));
                 };
             }
         };
         Ok(())
     }
-
-    fn prepare_items(&self, state: &State<C>) -> Items {
-        let mut items = Items::new();
-        if !state.living_room.coins[0] {
-            items.push(3.0, Interactables::Coin0);
-        }
-        if !state.living_room.coins[1] {
-            items.push(8.0, Interactables::Coin1);
-        }
-        if state.living_room.all_coins_collected() {
-            items.push(1.0, Interactables::ExitDoor);
-        }
-        items
-    }
 }
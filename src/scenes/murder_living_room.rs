@@ -1,7 +1,8 @@
-use super::{InteractableId, Item, Items, Scene};
-use crate::ctx::{Ctx, Effect, Music};
+use super::{Items, Room, Scene};
+use crate::ctx::{Ctx, Effect, Layer, Music};
 use crate::globals::GROUND_LEVEL;
 use crate::helper::CtxHelperExt;
+use crate::physics::Collider;
 use crate::sprite::{Actor, Blood, Npc, Tile};
 use crate::state::State;
 
@@ -10,40 +11,49 @@ use super::Scenes;
 #[derive(Default)]
 pub struct MurderLivingRoom;
 
-enum Interactables {
+const COUCH: Collider = Collider {
+    x: 6.0,
+    width: 1.0,
+    height: 0.5,
+    solid: true,
+};
+const DAD: Collider = Collider {
+    x: 5.375,
+    width: 0.25,
+    height: 2.0,
+    solid: true,
+};
+
+pub enum Interactables {
     ExitDoor,
     Dad,
 }
 
-impl Item for Interactables {
-    fn id(&self) -> InteractableId {
-        match self {
-            Self::ExitDoor => InteractableId(0),
-            Self::Dad => InteractableId(1),
-        }
-    }
-}
-
-impl From<InteractableId> for Interactables {
-    fn from(value: InteractableId) -> Self {
-        match value {
-            InteractableId(0) => Self::ExitDoor,
-            InteractableId(1) => Self::Dad,
-            InteractableId(_) => unreachable!(),
-        }
-    }
-}
-
 impl MurderLivingRoom {
     fn draw_house<C: Ctx>(&self, ctx: &mut C) {
         ctx.draw_wallpaper(&Tile::StripeWallpaper);
-        ctx.draw_sprite((1.0, GROUND_LEVEL), (1.0, 1.0), &Tile::DoorOpen);
-        ctx.draw_sprite((3.0, GROUND_LEVEL), (1.0, 1.0), &Tile::TreeDayPicture);
-        ctx.draw_sprite((4.0, GROUND_LEVEL), (1.0, 1.0), &Tile::HousePicture);
-        ctx.draw_sprite((6.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Couch);
+        ctx.draw_sprite(
+            Layer::Props,
+            (1.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::DoorOpen,
+        );
+        ctx.draw_sprite(
+            Layer::Props,
+            (3.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::TreeDayPicture,
+        );
+        ctx.draw_sprite(
+            Layer::Props,
+            (4.0, GROUND_LEVEL),
+            (1.0, 1.0),
+            &Tile::HousePicture,
+        );
+        ctx.draw_sprite(Layer::Props, (6.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Couch);
     }
 
-    fn draw_dad<C: Ctx>(&self, ctx: &mut C, state: &State<C>) {
+    fn draw_dad<C: Ctx>(&self, ctx: &mut C, state: &State) {
         let dad = if state.murder_living_room.dad_dead {
             Actor::Dad(Npc::Dead)
         } else if ctx.seconds_elapsed() % 1.0 < 0.5 {
@@ -52,30 +62,71 @@ impl MurderLivingRoom {
             Actor::Dad(Npc::IdleAlt)
         };
 
-        ctx.draw_sprite((5.0, GROUND_LEVEL), (1.0, 1.0), &dad);
+        ctx.draw_sprite(Layer::Actors, (5.0, GROUND_LEVEL), (1.0, 1.0), &dad);
 
         if state.murder_living_room.dad_dead {
-            ctx.draw_sprite((4.0, GROUND_LEVEL), (1.0, 1.0), &Blood::SplatterRight);
-            ctx.draw_sprite((5.0, GROUND_LEVEL), (1.0, 1.0), &Blood::SplatterCenter);
-            ctx.draw_sprite((6.0, GROUND_LEVEL), (1.0, 1.0), &Blood::SplatterLeft);
+            ctx.draw_sprite(
+                Layer::Decals,
+                (4.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Blood::SplatterRight,
+            );
+            ctx.draw_sprite(
+                Layer::Decals,
+                (5.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Blood::SplatterCenter,
+            );
+            ctx.draw_sprite(
+                Layer::Decals,
+                (6.0, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Blood::SplatterLeft,
+            );
         }
     }
 }
 
+impl Room for MurderLivingRoom {
+    type Item = Interactables;
+
+    fn colliders(&self, state: &State) -> Vec<Collider> {
+        let mut colliders = vec![COUCH];
+        if !state.murder_living_room.dad_dead {
+            colliders.push(DAD);
+        }
+        colliders
+    }
+
+    fn prepare_items(&self, state: &State) -> Items<Interactables> {
+        let mut items = Items::new();
+        items.push(5.0, Interactables::Dad);
+        if state.murder_living_room.dad_dead {
+            items.push(1.0, Interactables::ExitDoor);
+        }
+        items
+    }
+}
+
 impl<C: Ctx> Scene<C> for MurderLivingRoom {
-    fn draw(&self, ctx: &mut C, state: &crate::state::State<C>) {
+    fn draw(&self, ctx: &mut C, state: &crate::state::State) {
         self.draw_house(ctx);
         ctx.draw_ground();
         self.draw_dad(ctx, state);
     }
 
+    fn on_enter(&self, _ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        state.murder_living_room.murderous_intent = true;
+        Ok(())
+    }
+
     fn interact(
         &self,
         ctx: &mut C,
-        state: &mut crate::state::State<C>,
-        item: Box<dyn Item>,
+        state: &mut crate::state::State,
+        item: Interactables,
     ) -> Result<(), C::Error> {
-        match item.id().into() {
+        match item {
             Interactables::ExitDoor => {
                 ctx.play_effect(Effect::Interact)?;
                 state.scene_changed = Some((8.0, Scenes::Kitchen));
@@ -90,13 +141,4 @@ impl<C: Ctx> Scene<C> for MurderLivingRoom {
         };
         Ok(())
     }
-
-    fn prepare_items(&self, state: &State<C>) -> Items {
-        let mut items = Items::new();
-        items.push(5.0, Interactables::Dad);
-        if state.murder_living_room.dad_dead {
-            items.push(1.0, Interactables::ExitDoor);
-        }
-        items
-    }
 }
@@ -1,61 +1,50 @@
-use super::{InteractableId, Item, Items, Scene};
-use crate::ctx::{Ctx, Effect, Music, Rgb};
+use super::{Items, Room, Scene};
+use crate::ctx::{Ctx, Effect, Layer, Music, Rgb};
+use crate::cutscene::Cutscene;
 use crate::globals::GROUND_LEVEL;
 use crate::helper::CtxHelperExt;
+use crate::inventory::{ItemKind, Pickup, Requirement};
 use crate::sprite::Tile;
 use crate::state::{EndingChosen, State};
 
 use super::Scenes;
 
 const HOUSE_OFFSET: f64 = 6.0;
+const WIDTH: f64 = 14.0;
 
 #[derive(Default)]
 pub struct Outside;
 
-enum Interactables {
+pub enum Interactables {
     Bike,
     Door,
     Ascension,
-    Key,
+    Pickup(Pickup),
 }
 
-impl Item for Interactables {
-    fn id(&self) -> InteractableId {
-        match self {
-            Self::Bike => InteractableId(0),
-            Self::Door => InteractableId(1),
-            Self::Ascension => InteractableId(2),
-            Self::Key => InteractableId(3),
-        }
-    }
-}
-
-impl From<InteractableId> for Interactables {
-    fn from(value: InteractableId) -> Self {
-        match value {
-            InteractableId(0) => Self::Bike,
-            InteractableId(1) => Self::Door,
-            InteractableId(2) => Self::Ascension,
-            InteractableId(3) => Self::Key,
-            InteractableId(_) => unreachable!(),
-        }
-    }
-}
+const KEYS: [(f64, Pickup); 1] = [(3.0, Pickup::OutsideKey)];
+const DOOR: Requirement = Requirement::Has(ItemKind::Key);
 
 impl Outside {
-    fn draw_house<C: Ctx>(&self, ctx: &mut C, state: &State<C>) {
-        ctx.draw_sprite((1.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Bike);
+    fn draw_house<C: Ctx>(&self, ctx: &mut C, state: &State) {
+        ctx.draw_sprite(Layer::Props, (1.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Bike);
 
         for i in 0..=2 {
             ctx.draw_sprite(
+                Layer::Wallpaper,
                 (HOUSE_OFFSET + i as f64, GROUND_LEVEL),
                 (1.0, 1.0),
                 &Tile::HouseBrick,
             );
         }
 
-        for x in 0..10 {
-            ctx.draw_sprite((x as f64, GROUND_LEVEL), (1.0, 1.0), &Tile::Grass);
+        for x in 0..WIDTH as u32 {
+            ctx.draw_sprite(
+                Layer::Wallpaper,
+                (x as f64, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Tile::Grass,
+            );
         }
 
         let sun_tile = if state.child_room.child_dead() {
@@ -63,26 +52,29 @@ impl Outside {
         } else {
             Tile::Sun
         };
-        ctx.draw_sprite((1.0, 1.0), (1.0, 1.0), &sun_tile);
+        let sun_x = ctx.camera().parallax(1.0, 0.0);
+        ctx.draw_sprite(Layer::Wallpaper, (sun_x, 1.0), (1.0, 1.0), &sun_tile);
 
         [Tile::LeftTriangle, Tile::Block, Tile::RightTriangle]
             .into_iter()
             .enumerate()
             .for_each(|(offset, tile)| {
                 ctx.draw_sprite(
+                    Layer::Wallpaper,
                     (HOUSE_OFFSET + offset as f64, GROUND_LEVEL - 1.0),
                     (1.0, 1.0),
                     &tile,
                 );
             });
 
-        let door_texture = if state.outside.key_collected {
+        let door_texture = if state.meets(&DOOR) {
             Tile::DoorOpen
         } else {
             Tile::DoorClosed
         };
 
         ctx.draw_sprite(
+            Layer::Props,
             (HOUSE_OFFSET + 1.0, GROUND_LEVEL),
             (1.0, 1.0),
             &door_texture,
@@ -100,33 +92,55 @@ impl Outside {
                 Tile::Ascension3
             };
 
-            ctx.draw_sprite((3.0, -2.0), (1.0, 4.0), &sprite);
-            ctx.draw_sprite((3.0, 2.0), (1.0, 4.0), &sprite);
+            ctx.draw_sprite(Layer::Decals, (3.0, -2.0), (1.0, 4.0), &sprite);
+            ctx.draw_sprite(Layer::Decals, (3.0, 2.0), (1.0, 4.0), &sprite);
         }
 
-        if !state.outside.key_collected {
-            ctx.draw_item(&Tile::Key, 3.0);
+        ctx.draw_pickups(&state.inventory, &KEYS);
+    }
+
+    fn draw_surroundings<C: Ctx>(&self, ctx: &mut C) {
+        for x in [10.0, 12.0] {
+            ctx.draw_sprite(
+                Layer::Props,
+                (x, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Tile::TreeTrunk,
+            );
+            ctx.draw_sprite(
+                Layer::Props,
+                (x, GROUND_LEVEL - 1.0),
+                (1.0, 1.0),
+                &Tile::TreeLeaves,
+            );
         }
+
+        [
+            (4.0, 1.0, Tile::Cloud0),
+            (8.0, 2.0, Tile::Cloud1),
+            (12.0, 1.0, Tile::Cloud2),
+        ]
+        .into_iter()
+        .for_each(|(x, y, cloud)| {
+            let x = ctx.camera().parallax(x, 0.5);
+            ctx.draw_sprite(Layer::Wallpaper, (x, y), (1.0, 1.0), &cloud);
+        });
     }
 }
 
-impl<C: Ctx> Scene<C> for Outside {
-    fn draw(&self, ctx: &mut C, state: &crate::state::State<C>) {
-        if state.child_room.child_dead() {
-            ctx.draw_background_fill(Rgb(217, 87, 99));
-        } else {
-            ctx.draw_background_fill(Rgb(255, 255, 255));
-        }
-        self.draw_house(ctx, state);
-        ctx.draw_ground();
+impl Room for Outside {
+    type Item = Interactables;
+
+    fn width(&self) -> f64 {
+        WIDTH
     }
 
-    fn prepare_items(&self, state: &State<C>) -> Items {
+    fn prepare_items(&self, state: &State) -> Items<Interactables> {
         let mut items = Items::new();
-        if state.outside.key_collected {
+        if state.meets(&DOOR) {
             items.push(HOUSE_OFFSET + 1.0, Interactables::Door);
         } else {
-            items.push(3.0, Interactables::Key);
+            items.push_pickups(&state.inventory, &KEYS, Interactables::Pickup);
         }
         if state.child_room.child_dead() && state.ending_chosen.is_none() {
             items.push(3.0, Interactables::Ascension);
@@ -140,29 +154,46 @@ impl<C: Ctx> Scene<C> for Outside {
 
         items
     }
+}
+
+impl<C: Ctx> Scene<C> for Outside {
+    fn draw(&self, ctx: &mut C, state: &crate::state::State) {
+        if state.child_room.child_dead() {
+            ctx.draw_background_fill(Rgb(217, 87, 99));
+        } else {
+            ctx.draw_background_fill(Rgb(255, 255, 255));
+        }
+        self.draw_house(ctx, state);
+        self.draw_surroundings(ctx);
+        ctx.draw_ground();
+    }
+
+    fn on_enter(&self, ctx: &mut C, state: &mut State) -> Result<(), C::Error> {
+        if !state.child_room.child_dead() {
+            ctx.set_music(Music::Outside)?;
+        }
+        Ok(())
+    }
 
     fn interact(
         &self,
         ctx: &mut C,
-        state: &mut State<C>,
-        item: Box<dyn Item>,
+        state: &mut State,
+        item: Interactables,
     ) -> Result<(), C::Error> {
         ctx.play_effect(Effect::Interact)?;
-        match item.id().into() {
-            Interactables::Key => state.outside.key_collected = true,
+        match item {
+            Interactables::Pickup(pickup) => state.pick_up(pickup),
             Interactables::Ascension => {
                 state.ending_chosen = Some(EndingChosen::Ascended);
-                ctx.set_music(Music::Ascend)?;
+                state.cutscene = Some(Cutscene::ascension());
             }
             Interactables::Door => {
                 state.scene_changed = Some((1.into(), Scenes::Entryway));
-
-                if !state.living_room.has_escaped_dad {
-                    ctx.set_music(Music::Lemonhead)?;
-                }
             }
             Interactables::Bike => {
                 state.ending_chosen = Some(EndingChosen::Escaped);
+                state.cutscene = Some(Cutscene::escape());
             }
         };
         Ok(())
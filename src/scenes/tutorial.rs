@@ -1,95 +1,124 @@
-use super::{InteractableId, Item, Items, Scene};
+use super::{Items, Room, Scene};
 use crate::ctx::Ctx;
 use crate::ctx::Effect;
+use crate::ctx::Layer;
+use crate::ctx::Music;
 use crate::ctx::Rgb;
+use crate::cutscene::Cutscene;
 use crate::globals::GROUND_LEVEL;
 use crate::helper::CtxHelperExt;
+use crate::inventory::{Pickup, Requirement, TUTORIAL_COINS};
+use crate::locale::Message;
 use crate::sprite::Tile;
 use crate::state::State;
-
-use super::Scenes;
+use crate::text::{Align, TextStyle};
 
 #[derive(Default)]
 pub struct Tutorial;
 
-enum Interactables {
+pub enum Interactables {
     Bike,
-    Coin,
-}
-
-impl Item for Interactables {
-    fn id(&self) -> InteractableId {
-        match self {
-            Interactables::Bike => InteractableId(0),
-            Interactables::Coin => InteractableId(1),
-        }
-    }
+    Pickup(Pickup),
 }
 
-impl From<InteractableId> for Interactables {
-    fn from(value: InteractableId) -> Self {
-        match value {
-            InteractableId(0) => Interactables::Bike,
-            InteractableId(1) => Interactables::Coin,
-            InteractableId(_) => unreachable!(),
-        }
-    }
-}
+const COINS: [(f64, Pickup); 1] = [(4.0, Pickup::TutorialCoin)];
+const BIKE: Requirement = Requirement::AllOf(TUTORIAL_COINS);
 
 impl Tutorial {
     fn draw_scenery<C: Ctx>(&self, ctx: &mut C) {
-        ctx.draw_sprite((8.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Bike);
+        ctx.draw_sprite(Layer::Props, (8.0, GROUND_LEVEL), (1.0, 1.0), &Tile::Bike);
 
         for x in 0..10 {
-            ctx.draw_sprite((x as f64, GROUND_LEVEL), (1.0, 1.0), &Tile::Grass);
+            ctx.draw_sprite(
+                Layer::Wallpaper,
+                (x as f64, GROUND_LEVEL),
+                (1.0, 1.0),
+                &Tile::Grass,
+            );
         }
 
-        ctx.draw_sprite((1.0, 1.0), (1.0, 1.0), &Tile::Sun);
+        ctx.draw_sprite(Layer::Wallpaper, (1.0, 1.0), (1.0, 1.0), &Tile::Sun);
     }
 
-    fn draw_text<C: Ctx>(&self, ctx: &mut C, state: &State<C>) {
-        if !state.tutorial.coin {
-            ctx.draw_sprite((3.0, 2.0), (4.0, 1.0), &Tile::IntroductionText);
-            ctx.draw_sprite((1.0, 3.0), (8.0, 1.0), &Tile::IntroductionGoalsText);
+    fn draw_text<C: Ctx>(&self, ctx: &mut C, state: &State) {
+        let style = TextStyle {
+            align: Align::Center,
+            ..TextStyle::small(Rgb(38, 38, 38))
+        };
+        let language = ctx.language();
+        if !state.meets(&BIKE) {
+            ctx.draw_text(
+                Layer::Ui,
+                (5.0, 2.125),
+                language.text(Message::TutorialWelcome),
+                &style,
+            );
+            ctx.draw_text(
+                Layer::Ui,
+                (5.0, 3.125),
+                language.text(Message::TutorialGoals),
+                &style,
+            );
         } else {
-            ctx.draw_sprite((2.0, 2.5), (6.0, 1.0), &Tile::RememberText);
-            ctx.draw_sprite((6.0, 9.25), (1.0, 0.5), &Tile::VoicesText);
+            ctx.draw_text(
+                Layer::Ui,
+                (5.0, 2.625),
+                language.text(Message::TutorialRemember),
+                &style,
+            );
+            let voices = TextStyle {
+                pixel_size: 1.0 / 32.0,
+                align: Align::Right,
+                ..TextStyle::small(Rgb(170, 170, 170))
+            };
+            ctx.draw_text(
+                Layer::Ui,
+                (7.0, 9.375),
+                language.text(Message::TutorialVoices),
+                &voices,
+            );
         }
     }
 }
 
+impl Room for Tutorial {
+    type Item = Interactables;
+
+    fn prepare_items(&self, state: &State) -> Items<Interactables> {
+        let mut items = Items::new();
+        if state.meets(&BIKE) {
+            items.push(8.0, Interactables::Bike);
+        } else {
+            items.push_pickups(&state.inventory, &COINS, Interactables::Pickup);
+        };
+        items
+    }
+}
+
 impl<C: Ctx> Scene<C> for Tutorial {
-    fn draw(&self, ctx: &mut C, state: &crate::state::State<C>) {
+    fn draw(&self, ctx: &mut C, state: &crate::state::State) {
         ctx.draw_background_fill(Rgb(255, 255, 255));
         ctx.draw_ground();
         self.draw_scenery(ctx);
         self.draw_text(ctx, state);
-        if !state.tutorial.coin {
-            ctx.draw_item(&Tile::Coin, 4.0);
-        }
+        ctx.draw_pickups(&state.inventory, &COINS);
     }
 
-    fn prepare_items(&self, state: &State<C>) -> Items {
-        let mut items = Items::new();
-        if state.tutorial.coin {
-            items.push(8.0, Interactables::Bike);
-        } else {
-            items.push(4.0, Interactables::Coin);
-        };
-        items
+    fn on_enter(&self, ctx: &mut C, _state: &mut State) -> Result<(), C::Error> {
+        ctx.set_music(Music::Outside)
     }
 
     fn interact(
         &self,
         ctx: &mut C,
-        state: &mut State<C>,
-        item: Box<dyn Item>,
+        state: &mut State,
+        item: Interactables,
     ) -> Result<(), C::Error> {
         ctx.play_effect(Effect::Interact)?;
-        match item.id().into() {
-            Interactables::Coin => state.tutorial.coin = true,
+        match item {
+            Interactables::Pickup(pickup) => state.pick_up(pickup),
             Interactables::Bike => {
-                state.scene_changed = Some((1.0, Scenes::Outside));
+                state.cutscene = Some(Cutscene::ride_away());
             }
         }
         Ok(())
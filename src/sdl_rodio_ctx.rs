@@ -1,21 +1,28 @@
 use std::{
-    collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
-    time::Instant,
+    collections::{hash_map::Entry, HashMap, HashSet},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
 };
 
 use sdl2::{
     event::Event,
+    event::WindowEvent,
     image::{InitFlag, LoadTexture},
-    keyboard::Keycode,
+    keyboard::{Keycode, Mod},
     pixels::Color,
     rect::Rect,
-    render::WindowCanvas,
-    video::Window,
+    render::{BlendMode, Texture, TextureCreator, WindowCanvas},
+    video::{FullscreenType, Window, WindowContext},
     Sdl,
 };
 
 use crate::{
-    ctx::{Ctx, Rgb},
+    camera::Camera,
+    cli::Options,
+    ctx::{Ctx, Layer, Rgb},
+    locale::Language,
+    replay::{Frame, Recording},
+    settings::{Renderer, ScaleMode, Settings, WindowMode},
     sprite::Sprite,
 };
 use rodio::{Decoder, OutputStreamHandle, Sink, Source};
@@ -25,7 +32,7 @@ enum AudioEvent {
     Stop,
 }
 struct AudioInfo {
-    path: &'static str,
+    path: PathBuf,
     volume: f32,
     repeat: bool,
 }
@@ -45,11 +52,7 @@ fn audio_thread() -> std::sync::mpsc::Sender<AudioEvent> {
             return Err("unable to open audio channel".to_owned());
         };
         let mut sink = Sink::try_new(&stream_handle).map_err(|e| e.to_string())?;
-        loop {
-            let Ok(event) = receiver.recv() else {
-                break;
-            };
-
+        while let Ok(event) = receiver.recv() {
             let info = match event {
                 AudioEvent::Info(info) => info,
                 AudioEvent::Stop => {
@@ -60,8 +63,8 @@ fn audio_thread() -> std::sync::mpsc::Sender<AudioEvent> {
             sink = consume_sink(sink, &stream_handle)?;
             sink.set_volume(info.volume);
             let file = std::io::BufReader::new(
-                std::fs::File::open(info.path)
-                    .map_err(|_| format!("audio file at {} not found", info.path))?,
+                std::fs::File::open(&info.path)
+                    .map_err(|_| format!("audio file at {} not found", info.path.display()))?,
             );
             let source = Decoder::new(file).map_err(|e| e.to_string())?;
             if info.repeat {
@@ -77,6 +80,9 @@ fn audio_thread() -> std::sync::mpsc::Sender<AudioEvent> {
     sender
 }
 
+const RENDER_SIZE: (u32, u32) = (320, 320);
+const BORDER_COLOR: Rgb = Rgb(50, 50, 50);
+
 enum QueueItem {
     Sprite {
         sprite_path: &'static str,
@@ -84,166 +90,455 @@ enum QueueItem {
         sprite_size: (u32, u32),
         position: (f64, f64),
         size: (f64, f64),
+        tint: Rgb,
+        alpha: u8,
     },
-    FillBackground(Rgb),
-    DrawRect {
+    Rect {
         color: Rgb,
+        alpha: u8,
         position: (f64, f64),
         size: (f64, f64),
     },
 }
 
-pub struct SdlRodioCtx {
+pub struct SdlRodioCtx<'a> {
     sdl: Sdl,
     canvas: WindowCanvas,
+    texture_creator: &'a TextureCreator<WindowContext>,
+    render_target: Texture<'a>,
     playing_music: Option<crate::ctx::Music>,
     keys_down: HashSet<crate::ctx::Key>,
-    render_queue: VecDeque<QueueItem>,
+    camera: Camera,
+    settings: Settings,
+    saved_settings: Settings,
+    fullscreen_mode: WindowMode,
+    background_fill: Option<Rgb>,
+    render_queue: Vec<(Layer, QueueItem)>,
     music_handle: std::sync::mpsc::Sender<AudioEvent>,
     effect_handle: std::sync::mpsc::Sender<AudioEvent>,
-    started: Instant,
+    text_input: String,
+    clock: (Instant, f64),
+    time_scale: f64,
+    frame_started: Instant,
+    muted: bool,
+    assets: PathBuf,
+    rng: u64,
+    recording: Option<(PathBuf, Recording)>,
+    replay: Option<(Recording, usize)>,
 }
 
-impl SdlRodioCtx {
-    pub fn new() -> Result<Self, String> {
+impl<'a> SdlRodioCtx<'a> {
+    pub fn run<T>(
+        saved_settings: Settings,
+        options: &Options,
+        run: impl FnOnce(&mut SdlRodioCtx<'_>) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let settings = options.overrides.apply(&saved_settings);
         let sdl = sdl2::init()?;
-        let window = Self::prepare_window(&sdl)?;
-        let canvas = Self::prepare_canvas(window)?;
+        let window = Self::prepare_window(&sdl, &settings)?;
+        let canvas = match Self::prepare_canvas(window, &settings, settings.renderer) {
+            Ok(canvas) => canvas,
+            Err(error) if settings.renderer == Renderer::Accelerated => {
+                eprintln!("accelerated renderer unavailable ({error}), falling back to software");
+                let window = Self::prepare_window(&sdl, &settings)?;
+                Self::prepare_canvas(window, &settings, Renderer::Software)?
+            }
+            Err(error) => return Err(error),
+        };
+        let texture_creator = canvas.texture_creator();
+        let mut ctx = SdlRodioCtx::new(
+            sdl,
+            canvas,
+            &texture_creator,
+            settings,
+            saved_settings,
+            options,
+        )?;
+        run(&mut ctx)
+    }
+
+    fn new(
+        sdl: Sdl,
+        canvas: WindowCanvas,
+        texture_creator: &'a TextureCreator<WindowContext>,
+        settings: Settings,
+        saved_settings: Settings,
+        options: &Options,
+    ) -> Result<Self, String> {
+        let replay = match &options.replay {
+            Some(path) => Some((Recording::load(path)?, 0)),
+            None => None,
+        };
+        let seed = match (&replay, options.seed) {
+            (Some((recording, _)), _) => recording.seed,
+            (None, Some(seed)) => seed,
+            (None, None) => SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |time| time.as_nanos() as u64),
+        };
+        let recording = options
+            .record
+            .clone()
+            .map(|path| (path, Recording::new(seed)));
+        let render_target = texture_creator
+            .create_texture_target(None, RENDER_SIZE.0, RENDER_SIZE.1)
+            .map_err(|e| e.to_string())?;
+        let fullscreen_mode = match settings.window_mode {
+            WindowMode::Windowed => WindowMode::Borderless,
+            mode => mode,
+        };
 
         Ok(Self {
             sdl,
             canvas,
+            texture_creator,
+            render_target,
             playing_music: None,
             music_handle: audio_thread(),
             effect_handle: audio_thread(),
             keys_down: HashSet::new(),
-            render_queue: VecDeque::new(),
-            started: Instant::now(),
+            camera: Camera::new(),
+            settings,
+            saved_settings,
+            fullscreen_mode,
+            background_fill: None,
+            render_queue: Vec::new(),
+            text_input: String::new(),
+            clock: (Instant::now(), 0.0),
+            time_scale: 1.0,
+            frame_started: Instant::now(),
+            muted: options.mute,
+            assets: options.assets.clone(),
+            rng: seed ^ 0x9e37_79b9_7f4a_7c15,
+            recording,
+            replay,
         })
     }
 
-    fn prepare_window(sdl: &Sdl) -> Result<Window, String> {
+    fn prepare_window(sdl: &Sdl, settings: &Settings) -> Result<Window, String> {
         let video_subsystem = sdl.video()?;
         let _image_context = sdl2::image::init(InitFlag::PNG | InitFlag::JPG)?;
-        let window = video_subsystem
-            .window("the adventures of lemonhead", 720, 720)
-            .position_centered()
-            .resizable()
-            .maximized()
-            .build()
-            .map_err(|e| e.to_string())?;
+        let (width, height) = settings.window_size;
+        let bounds = match video_subsystem.display_bounds(settings.display) {
+            Ok(bounds) => bounds,
+            Err(error) => {
+                eprintln!(
+                    "display {} unavailable ({error}), using display 0",
+                    settings.display
+                );
+                video_subsystem.display_bounds(0)?
+            }
+        };
+        let (x, y) = settings
+            .window_position
+            .filter(|(x, y)| bounds.contains_point((*x, *y)))
+            .unwrap_or((
+                bounds.x() + (bounds.width() as i32 - width as i32) / 2,
+                bounds.y() + (bounds.height() as i32 - height as i32) / 2,
+            ));
+
+        let mut builder = video_subsystem.window("the adventures of lemonhead", width, height);
+        builder.position(x, y).resizable();
+        if settings.maximized {
+            builder.maximized();
+        }
+        let mut window = builder.build().map_err(|e| e.to_string())?;
+        window.set_fullscreen(fullscreen_type(settings.window_mode))?;
         Ok(window)
     }
 
-    fn prepare_canvas(window: Window) -> Result<WindowCanvas, String> {
-        window
-            .into_canvas()
-            .software()
-            .build()
-            .map_err(|e| e.to_string())
+    fn prepare_canvas(
+        window: Window,
+        settings: &Settings,
+        renderer: Renderer,
+    ) -> Result<WindowCanvas, String> {
+        let mut builder = window.into_canvas().target_texture();
+        builder = match renderer {
+            Renderer::Software => builder.software(),
+            Renderer::Accelerated => builder.accelerated(),
+        };
+        if settings.vsync {
+            builder = builder.present_vsync();
+        }
+        builder.build().map_err(|e| e.to_string())
+    }
+
+    fn toggle_fullscreen(&mut self) -> Result<(), String> {
+        let mode = match self.settings.window_mode {
+            WindowMode::Windowed => self.fullscreen_mode,
+            WindowMode::Fullscreen | WindowMode::Borderless => WindowMode::Windowed,
+        };
+        self.canvas
+            .window_mut()
+            .set_fullscreen(fullscreen_type(mode))?;
+        self.settings.window_mode = mode;
+        self.saved_settings.window_mode = mode;
+        Ok(())
+    }
+
+    fn remember_window(&mut self, event: WindowEvent) {
+        if self.settings.window_mode != WindowMode::Windowed {
+            return;
+        }
+        for settings in [&mut self.settings, &mut self.saved_settings] {
+            match event {
+                WindowEvent::Moved(x, y) => settings.window_position = Some((x, y)),
+                WindowEvent::Resized(width, height) if !settings.maximized => {
+                    settings.window_size = (width as u32, height as u32);
+                }
+                WindowEvent::Maximized => settings.maximized = true,
+                WindowEvent::Restored => settings.maximized = false,
+                _ => {}
+            }
+        }
+    }
+
+    fn target_rect(&self) -> Result<Rect, String> {
+        let (win_width, win_height) = self.canvas.output_size()?;
+        let (target_width, target_height) = RENDER_SIZE;
+        let fit = f64::min(
+            f64::from(win_width) / f64::from(target_width),
+            f64::from(win_height) / f64::from(target_height),
+        );
+        let scale = match self.settings.scale_mode {
+            ScaleMode::PixelPerfect => fit.floor().max(1.0),
+            ScaleMode::Letterbox => fit,
+            ScaleMode::Stretch => return Ok(Rect::new(0, 0, win_width, win_height)),
+        };
+        let width = (f64::from(target_width) * scale) as u32;
+        let height = (f64::from(target_height) * scale) as u32;
+        Ok(Rect::new(
+            (win_width as i32 - width as i32) / 2,
+            (win_height as i32 - height as i32) / 2,
+            width,
+            height,
+        ))
+    }
+
+    fn replay_frame(&mut self) {
+        let Some((recording, index)) = &mut self.replay else {
+            return;
+        };
+        let Some(frame) = recording.frames.get(*index) else {
+            self.replay = None;
+            return;
+        };
+        *index += 1;
+        let quit = self.keys_down.contains(&crate::ctx::Key::Quit);
+        self.keys_down = frame.keys.iter().copied().collect();
+        if quit {
+            self.keys_down.insert(crate::ctx::Key::Quit);
+        }
+        self.text_input.clone_from(&frame.text);
+        self.clock = (Instant::now(), frame.time);
+    }
+
+    fn record_frame(&mut self) {
+        let time = self.seconds_elapsed();
+        let Some((_, recording)) = &mut self.recording else {
+            return;
+        };
+        recording.frames.push(Frame {
+            time,
+            keys: self.keys_down.iter().copied().collect(),
+            text: self.text_input.clone(),
+        });
     }
 
     fn draw_queue(&mut self) -> Result<(), String> {
-        let texture_creator = self.canvas.texture_creator();
+        let texture_creator = self.texture_creator;
         let mut texture_cache = HashMap::new();
-        loop {
-            let Some(item) = self.render_queue.pop_front() else {
-                break Ok(());
-            };
-            match item {
-                QueueItem::Sprite {
-                    sprite_path,
-                    sprite_offset,
-                    sprite_size,
-                    position,
-                    size,
-                } => {
-                    if let Entry::Vacant(entry) = texture_cache.entry(sprite_path) {
-                        let texture = texture_creator.load_texture(sprite_path)?;
-                        entry.insert(texture);
-                    }
-
-                    let texture = texture_cache.get(&sprite_path).expect("we have to insert");
-
-                    self.canvas.copy(
-                        texture,
-                        Rect::new(
-                            sprite_offset.0 as i32,
-                            sprite_offset.1 as i32,
-                            sprite_size.0,
-                            sprite_size.1,
-                        ),
-                        Rect::new(
-                            position.0 as i32,
-                            position.1 as i32,
-                            size.0 as u32,
-                            size.1 as u32,
-                        ),
-                    )?;
-                }
-                QueueItem::FillBackground(color) => {
-                    self.canvas
-                        .set_draw_color(Color::RGB(color.0, color.1, color.2));
-                    self.canvas.clear();
-                }
-                QueueItem::DrawRect {
-                    color,
-                    position,
-                    size,
-                } => {
-                    self.canvas
-                        .set_draw_color(Color::RGB(color.0, color.1, color.2));
-                    self.canvas.fill_rect(Rect::new(
-                        position.0 as i32,
-                        position.1 as i32,
-                        size.0 as u32,
-                        size.1 as u32,
-                    ))?;
-                }
+        let background = self.background_fill.take().unwrap_or(Rgb(0, 0, 0));
+        let mut queue = std::mem::take(&mut self.render_queue);
+        let assets = &self.assets;
+        queue.sort_by_key(|(layer, _)| *layer);
+
+        let mut result = Ok(());
+        self.canvas
+            .with_texture_canvas(&mut self.render_target, |canvas| {
+                canvas.set_draw_color(Color::RGB(background.0, background.1, background.2));
+                canvas.clear();
+                result = queue.into_iter().try_for_each(|(_, item)| {
+                    draw_item(canvas, texture_creator, &mut texture_cache, assets, item)
+                });
+            })
+            .map_err(|e| e.to_string())?;
+        result?;
+
+        let target_rect = self.target_rect()?;
+        let border = BORDER_COLOR;
+        self.canvas
+            .set_draw_color(Color::RGB(border.0, border.1, border.2));
+        self.canvas.clear();
+        self.canvas.copy(&self.render_target, None, target_rect)
+    }
+}
+
+impl Drop for SdlRodioCtx<'_> {
+    fn drop(&mut self) {
+        if let Err(error) = self.saved_settings.save() {
+            eprintln!("unable to save settings: {error}");
+        }
+        if let Some((path, recording)) = &self.recording {
+            if let Err(error) = recording.save(path) {
+                eprintln!("unable to save recording: {error}");
             }
         }
     }
 }
 
-impl Ctx for SdlRodioCtx {
+fn fullscreen_type(mode: WindowMode) -> FullscreenType {
+    match mode {
+        WindowMode::Windowed => FullscreenType::Off,
+        WindowMode::Fullscreen => FullscreenType::True,
+        WindowMode::Borderless => FullscreenType::Desktop,
+    }
+}
+
+fn screen_rect(position: (f64, f64), size: (f64, f64)) -> Rect {
+    Rect::new(
+        position.0.round() as i32,
+        position.1.round() as i32,
+        size.0.round() as u32,
+        size.1.round() as u32,
+    )
+}
+
+fn asset_path(assets: &Path, path: &str) -> PathBuf {
+    assets.join(path.strip_prefix("assets/").unwrap_or(path))
+}
+
+fn draw_item<'a>(
+    canvas: &mut WindowCanvas,
+    texture_creator: &'a TextureCreator<WindowContext>,
+    texture_cache: &mut HashMap<&'static str, Texture<'a>>,
+    assets: &Path,
+    item: QueueItem,
+) -> Result<(), String> {
+    match item {
+        QueueItem::Sprite {
+            sprite_path,
+            sprite_offset,
+            sprite_size,
+            position,
+            size,
+            tint,
+            alpha,
+        } => {
+            if let Entry::Vacant(entry) = texture_cache.entry(sprite_path) {
+                let texture = texture_creator.load_texture(asset_path(assets, sprite_path))?;
+                entry.insert(texture);
+            }
+
+            let texture = texture_cache
+                .get_mut(&sprite_path)
+                .expect("we have to insert");
+            texture.set_color_mod(tint.0, tint.1, tint.2);
+            texture.set_alpha_mod(alpha);
+
+            canvas.copy(
+                texture,
+                Rect::new(
+                    sprite_offset.0 as i32,
+                    sprite_offset.1 as i32,
+                    sprite_size.0,
+                    sprite_size.1,
+                ),
+                screen_rect(position, size),
+            )
+        }
+        QueueItem::Rect {
+            color,
+            alpha,
+            position,
+            size,
+        } => {
+            canvas.set_blend_mode(BlendMode::Blend);
+            canvas.set_draw_color(Color::RGBA(color.0, color.1, color.2, alpha));
+            canvas.fill_rect(screen_rect(position, size))
+        }
+    }
+}
+
+impl Ctx for SdlRodioCtx<'_> {
     type Error = String;
 
     fn draw_background_fill(&mut self, color: crate::ctx::Rgb) {
-        self.render_queue
-            .push_back(QueueItem::FillBackground(color));
+        self.background_fill = Some(color);
     }
 
-    fn draw_screen_rect(
+    fn draw_sprite_alpha(
         &mut self,
-        color: crate::ctx::Rgb,
+        layer: Layer,
         position: (f64, f64),
         size: (f64, f64),
+        sprite: &impl Sprite,
+        tint: Rgb,
+        alpha: u8,
     ) {
-        self.render_queue.push_back(QueueItem::DrawRect {
-            color,
-            position,
-            size,
-        });
+        let position = self.to_screen_position(self.to_view_position(layer, position));
+        let size = self.to_screen_scale(size);
+        self.render_queue.push((
+            layer,
+            QueueItem::Sprite {
+                sprite_path: sprite.path(),
+                sprite_offset: sprite.offset(),
+                sprite_size: sprite.size(),
+                position,
+                size,
+                tint,
+                alpha,
+            },
+        ));
     }
 
-    fn draw_sprite(&mut self, position: (f64, f64), size: (f64, f64), sprite: &impl Sprite) {
-        let position = self.to_screen_position(position);
+    fn draw_rect_alpha(
+        &mut self,
+        layer: Layer,
+        color: Rgb,
+        alpha: u8,
+        position: (f64, f64),
+        size: (f64, f64),
+    ) {
+        let position = self.to_screen_position(self.to_view_position(layer, position));
         let size = self.to_screen_scale(size);
-        self.render_queue.push_back(QueueItem::Sprite {
-            sprite_path: sprite.path(),
-            sprite_offset: sprite.offset(),
-            sprite_size: sprite.size(),
-            position,
-            size,
-        });
+        self.render_queue.push((
+            layer,
+            QueueItem::Rect {
+                color,
+                alpha,
+                position,
+                size,
+            },
+        ));
     }
 
     fn key_down(&self, key: crate::ctx::Key) -> bool {
         self.keys_down.contains(&key)
     }
 
+    fn text_input(&self) -> &str {
+        &self.text_input
+    }
+
+    fn playing_music(&self) -> Option<crate::ctx::Music> {
+        self.playing_music
+    }
+
+    fn render_queue_len(&self) -> usize {
+        self.render_queue.len()
+    }
+
     fn setup(&mut self) -> Result<(), Self::Error> {
         self.keys_down.remove(&Key::Interact);
+        self.keys_down.remove(&Key::Jump);
+        self.keys_down.remove(&Key::Debug);
+        self.keys_down.remove(&Key::Hud);
+        self.keys_down.remove(&Key::Inventory);
+        self.keys_down.remove(&Key::Console);
+        self.keys_down.remove(&Key::Backspace);
+        self.keys_down.remove(&Key::Submit);
+        self.text_input.clear();
         use crate::ctx::Key;
         for event in self.sdl.event_pump()?.poll_iter() {
             match event {
@@ -254,11 +549,77 @@ impl Ctx for SdlRodioCtx {
                 } => {
                     self.keys_down.insert(Key::Quit);
                 }
+                Event::KeyDown {
+                    keycode: Some(Keycode::F9),
+                    ..
+                } => {
+                    self.settings.scale_mode = self.settings.scale_mode.next();
+                    self.saved_settings.scale_mode = self.settings.scale_mode;
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::F3),
+                    repeat: false,
+                    ..
+                } => {
+                    self.keys_down.insert(Key::Debug);
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::H),
+                    repeat: false,
+                    ..
+                } => {
+                    self.keys_down.insert(Key::Hud);
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::Tab),
+                    repeat: false,
+                    ..
+                } => {
+                    self.keys_down.insert(Key::Inventory);
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::Backquote),
+                    repeat: false,
+                    ..
+                } => {
+                    self.keys_down.insert(Key::Console);
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::Backspace),
+                    ..
+                } => {
+                    self.keys_down.insert(Key::Backspace);
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::Return),
+                    keymod,
+                    repeat: false,
+                    ..
+                } if !keymod.intersects(Mod::LALTMOD | Mod::RALTMOD) => {
+                    self.keys_down.insert(Key::Submit);
+                }
+                Event::TextInput { text, .. } => {
+                    self.text_input
+                        .extend(text.chars().filter(|&character| character != '`'));
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::F11),
+                    repeat: false,
+                    ..
+                } => self.toggle_fullscreen()?,
+                Event::KeyDown {
+                    keycode: Some(Keycode::Return),
+                    keymod,
+                    repeat: false,
+                    ..
+                } if keymod.intersects(Mod::LALTMOD | Mod::RALTMOD) => self.toggle_fullscreen()?,
+                Event::Window { win_event, .. } => self.remember_window(win_event),
                 Event::KeyDown {
                     keycode: Some(key), ..
                 } => {
                     let key = match key {
                         Keycode::Space => Key::Interact,
+                        Keycode::W | Keycode::Up => Key::Jump,
                         Keycode::A | Keycode::Left => Key::Left,
                         Keycode::D | Keycode::Right => Key::Right,
                         _ => continue,
@@ -270,6 +631,7 @@ impl Ctx for SdlRodioCtx {
                 } => {
                     let key = match key {
                         Keycode::Space => Key::Interact,
+                        Keycode::W | Keycode::Up => Key::Jump,
                         Keycode::A | Keycode::Left => Key::Left,
                         Keycode::D | Keycode::Right => Key::Right,
                         _ => continue,
@@ -279,27 +641,49 @@ impl Ctx for SdlRodioCtx {
                 _ => {}
             }
         }
+        self.replay_frame();
+        self.record_frame();
         Ok(())
     }
 
     fn finish(&mut self) -> Result<(), Self::Error> {
-        self.draw_border();
         self.draw_queue()?;
         self.canvas.present();
-        std::thread::sleep(std::time::Duration::new(0, 1_000_000_000u32 / 60));
+        if self.settings.fps > 0 {
+            let budget = Duration::from_secs_f64(1.0 / f64::from(self.settings.fps));
+            if let Some(remaining) = budget.checked_sub(self.frame_started.elapsed()) {
+                std::thread::sleep(remaining);
+            }
+        }
+        self.frame_started = Instant::now();
         Ok(())
     }
 
-    fn window_size(&self) -> (f64, f64) {
-        let (x, y) = self.canvas.window().size();
-        (x as f64, y as f64)
+    fn render_size(&self) -> (f64, f64) {
+        let (x, y) = RENDER_SIZE;
+        (f64::from(x), f64::from(y))
+    }
+
+    fn camera(&self) -> &Camera {
+        &self.camera
+    }
+
+    fn language(&self) -> Language {
+        self.settings.language
+    }
+
+    fn camera_mut(&mut self) -> &mut Camera {
+        &mut self.camera
     }
 
     fn play_effect(&mut self, effect: crate::ctx::Effect) -> Result<(), Self::Error> {
+        if self.muted {
+            return Ok(());
+        }
         self.effect_handle
             .send(AudioEvent::Info(AudioInfo {
                 repeat: false,
-                path: effect.path(),
+                path: asset_path(&self.assets, effect.path()),
                 volume: effect.volume(),
             }))
             .map_err(|e| e.to_string())
@@ -313,10 +697,14 @@ impl Ctx for SdlRodioCtx {
         {
             return Ok(());
         }
+        if self.muted {
+            self.playing_music = Some(music);
+            return Ok(());
+        }
         self.music_handle
             .send(AudioEvent::Info(AudioInfo {
                 repeat: true,
-                path: music.path(),
+                path: asset_path(&self.assets, music.path()),
                 volume: music.volume(),
             }))
             .map_err(|e| e.to_string())?;
@@ -333,6 +721,19 @@ impl Ctx for SdlRodioCtx {
     }
 
     fn seconds_elapsed(&self) -> f64 {
-        (Instant::now() - self.started).as_secs_f64()
+        let (anchor, offset) = self.clock;
+        offset + anchor.elapsed().as_secs_f64() * self.time_scale
+    }
+
+    fn set_time_scale(&mut self, scale: f64) {
+        self.clock = (Instant::now(), self.seconds_elapsed());
+        self.time_scale = scale;
+    }
+
+    fn random(&mut self) -> f64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng >> 11) as f64 / (1u64 << 53) as f64
     }
 }
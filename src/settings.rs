@@ -0,0 +1,191 @@
+use crate::{locale::Language, storage};
+
+const SETTINGS_FILE: &str = "settings.cfg";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum WindowMode {
+    Windowed,
+    Fullscreen,
+    Borderless,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Renderer {
+    Software,
+    Accelerated,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ScaleMode {
+    PixelPerfect,
+    Letterbox,
+    Stretch,
+}
+
+#[derive(Clone)]
+pub struct Settings {
+    pub window_mode: WindowMode,
+    pub scale_mode: ScaleMode,
+    pub renderer: Renderer,
+    pub display: i32,
+    pub vsync: bool,
+    pub fps: u32,
+    pub maximized: bool,
+    pub window_size: (u32, u32),
+    pub window_position: Option<(i32, i32)>,
+    pub language: Language,
+    pub speedrun_timer: bool,
+}
+
+impl WindowMode {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "windowed" => Some(WindowMode::Windowed),
+            "fullscreen" => Some(WindowMode::Fullscreen),
+            "borderless" => Some(WindowMode::Borderless),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            WindowMode::Windowed => "windowed",
+            WindowMode::Fullscreen => "fullscreen",
+            WindowMode::Borderless => "borderless",
+        }
+    }
+}
+
+impl Renderer {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "software" => Some(Renderer::Software),
+            "accelerated" => Some(Renderer::Accelerated),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Renderer::Software => "software",
+            Renderer::Accelerated => "accelerated",
+        }
+    }
+}
+
+impl ScaleMode {
+    pub fn next(self) -> Self {
+        match self {
+            ScaleMode::PixelPerfect => ScaleMode::Letterbox,
+            ScaleMode::Letterbox => ScaleMode::Stretch,
+            ScaleMode::Stretch => ScaleMode::PixelPerfect,
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pixel-perfect" => Some(ScaleMode::PixelPerfect),
+            "letterbox" => Some(ScaleMode::Letterbox),
+            "stretch" => Some(ScaleMode::Stretch),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            ScaleMode::PixelPerfect => "pixel-perfect",
+            ScaleMode::Letterbox => "letterbox",
+            ScaleMode::Stretch => "stretch",
+        }
+    }
+}
+
+fn parse_pair<T: std::str::FromStr>(value: &str) -> Option<(T, T)> {
+    let (left, right) = value.split_once(',')?;
+    Some((left.trim().parse().ok()?, right.trim().parse().ok()?))
+}
+
+impl Settings {
+    pub fn load() -> Self {
+        let mut settings = Self {
+            window_mode: WindowMode::Windowed,
+            scale_mode: ScaleMode::PixelPerfect,
+            renderer: Renderer::Accelerated,
+            display: 0,
+            vsync: true,
+            fps: 60,
+            maximized: true,
+            window_size: (720, 720),
+            window_position: None,
+            language: Language::detect(),
+            speedrun_timer: false,
+        };
+        for (key, value) in storage::read_pairs(SETTINGS_FILE) {
+            match key.as_str() {
+                "window_mode" => {
+                    if let Some(mode) = WindowMode::parse(&value) {
+                        settings.window_mode = mode;
+                    }
+                }
+                "scale_mode" => {
+                    if let Some(mode) = ScaleMode::parse(&value) {
+                        settings.scale_mode = mode;
+                    }
+                }
+                "renderer" => {
+                    if let Some(renderer) = Renderer::parse(&value) {
+                        settings.renderer = renderer;
+                    }
+                }
+                "fps" => {
+                    if let Ok(fps) = value.parse() {
+                        settings.fps = fps;
+                    }
+                }
+                "display" => {
+                    if let Ok(display) = value.parse() {
+                        settings.display = display;
+                    }
+                }
+                "vsync" => settings.vsync = value == "true",
+                "maximized" => settings.maximized = value == "true",
+                "speedrun_timer" => settings.speedrun_timer = value == "true",
+                "window_size" => {
+                    if let Some(size) = parse_pair(&value) {
+                        settings.window_size = size;
+                    }
+                }
+                "window_position" => settings.window_position = parse_pair(&value),
+                "language" => {
+                    if let Some(language) = Language::parse(&value) {
+                        settings.language = language;
+                    }
+                }
+                _ => {}
+            }
+        }
+        settings
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut pairs = vec![
+            ("window_mode", self.window_mode.name().to_owned()),
+            ("scale_mode", self.scale_mode.name().to_owned()),
+            ("renderer", self.renderer.name().to_owned()),
+            ("display", self.display.to_string()),
+            ("vsync", self.vsync.to_string()),
+            ("fps", self.fps.to_string()),
+            ("maximized", self.maximized.to_string()),
+            ("language", self.language.name().to_owned()),
+            ("speedrun_timer", self.speedrun_timer.to_string()),
+            (
+                "window_size",
+                format!("{},{}", self.window_size.0, self.window_size.1),
+            ),
+        ];
+        if let Some((x, y)) = self.window_position {
+            pairs.push(("window_position", format!("{x},{y}")));
+        }
+        storage::write_pairs(SETTINGS_FILE, &pairs)
+    }
+}
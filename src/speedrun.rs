@@ -0,0 +1,337 @@
+use crate::{
+    camera::VIEW_WIDTH,
+    ctx::{Ctx, Layer, Rgb},
+    game::{GameResult, Lemonhead},
+    sprite::{Actor, ActorState},
+    storage,
+    text::{Align, Spacing, TextStyle},
+};
+
+const ROUTES: [&str; 2] = ["escaped", "ascended"];
+const SAMPLE_SECONDS: f64 = 0.05;
+const DELTA_SECONDS: f64 = 3.0;
+
+struct Sample {
+    time: f64,
+    scene: String,
+    x: f64,
+    y: f64,
+}
+
+struct Route {
+    name: &'static str,
+    personal_best: Vec<(String, f64)>,
+    ghost: Vec<Sample>,
+}
+
+pub struct Speedrun {
+    enabled: bool,
+    time: f64,
+    splits: Vec<(String, f64)>,
+    routes: Vec<Route>,
+    delta: Option<(f64, f64)>,
+    path: Vec<Sample>,
+    checkpoint: (usize, usize),
+}
+
+impl Sample {
+    fn parse(scene: String, value: &str) -> Option<Self> {
+        let mut parts = value.split(',').map(str::parse);
+        let (Some(Ok(time)), Some(Ok(x)), Some(Ok(y))) = (parts.next(), parts.next(), parts.next())
+        else {
+            return None;
+        };
+        Some(Self { time, scene, x, y })
+    }
+}
+
+impl Route {
+    fn load(name: &'static str) -> Self {
+        let personal_best = storage::read_pairs(&format!("splits-{name}.cfg"))
+            .into_iter()
+            .filter_map(|(name, time)| Some((name, time.parse().ok()?)))
+            .collect();
+        let ghost = storage::read_pairs(&format!("ghost-{name}.cfg"))
+            .into_iter()
+            .filter_map(|(scene, value)| Sample::parse(scene, &value))
+            .collect();
+        Self {
+            name,
+            personal_best,
+            ghost,
+        }
+    }
+
+    fn save(&self) {
+        let pairs: Vec<_> = self
+            .personal_best
+            .iter()
+            .map(|(name, time)| (name.as_str(), time.to_string()))
+            .collect();
+        if let Err(error) = storage::write_pairs(&format!("splits-{}.cfg", self.name), &pairs) {
+            eprintln!("unable to save personal best: {error}");
+        }
+        let samples: Vec<_> = self
+            .ghost
+            .iter()
+            .map(|sample| {
+                let value = format!("{},{},{}", sample.time, sample.x, sample.y);
+                (sample.scene.as_str(), value)
+            })
+            .collect();
+        if let Err(error) = storage::write_pairs(&format!("ghost-{}.cfg", self.name), &samples) {
+            eprintln!("unable to save ghost: {error}");
+        }
+        let livesplit = format!("lemonhead-{}.lss", self.name);
+        if let Err(error) = storage::write_file(&livesplit, &self.livesplit()) {
+            eprintln!("unable to export splits: {error}");
+        }
+    }
+
+    fn livesplit(&self) -> String {
+        let segments: String = self
+            .personal_best
+            .iter()
+            .map(|(name, time)| {
+                format!(
+                    "    <Segment>\n      <Name>{name}</Name>\n      <Icon />\n      \
+                     <SplitTimes>\n        <SplitTime name=\"Personal Best\">\n          \
+                     <GameTime>{}</GameTime>\n        </SplitTime>\n      </SplitTimes>\n      \
+                     <BestSegmentTime />\n      <SegmentHistory />\n    </Segment>\n",
+                    livesplit_time(*time)
+                )
+            })
+            .collect();
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Run version=\"1.7.0\">\n  \
+             <GameIcon />\n  <GameName>The Adventures of Lemonhead</GameName>\n  \
+             <CategoryName>{}</CategoryName>\n  <Offset>00:00:00</Offset>\n  \
+             <AttemptCount>0</AttemptCount>\n  <AttemptHistory />\n  \
+             <Segments>\n{segments}  </Segments>\n  <AutoSplitterSettings />\n</Run>\n",
+            self.name
+        )
+    }
+}
+
+fn format_time(seconds: f64) -> String {
+    let centiseconds = (seconds * 100.0) as u64;
+    format!(
+        "{}:{:02}.{:02}",
+        centiseconds / 6000,
+        centiseconds / 100 % 60,
+        centiseconds % 100
+    )
+}
+
+fn format_delta(delta: f64) -> String {
+    let sign = if delta < 0.0 { '-' } else { '+' };
+    format!("{sign}{:.2}", delta.abs())
+}
+
+fn livesplit_time(seconds: f64) -> String {
+    let ticks = (seconds * 10_000_000.0) as u64;
+    format!(
+        "{:02}:{:02}:{:02}.{:07}",
+        ticks / 36_000_000_000,
+        ticks / 600_000_000 % 60,
+        ticks / 10_000_000 % 60,
+        ticks % 10_000_000
+    )
+}
+
+impl Speedrun {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            time: 0.0,
+            splits: Vec::new(),
+            routes: ROUTES.into_iter().map(Route::load).collect(),
+            delta: None,
+            path: Vec::new(),
+            checkpoint: (0, 0),
+        }
+    }
+
+    fn rival(&self) -> Option<&Route> {
+        let index = self.splits.len();
+        self.routes
+            .iter()
+            .filter(|route| {
+                route.personal_best.len() > index
+                    && route
+                        .personal_best
+                        .iter()
+                        .zip(&self.splits)
+                        .all(|((best, _), (name, _))| best == name)
+            })
+            .min_by(|a, b| {
+                a.personal_best[index]
+                    .1
+                    .total_cmp(&b.personal_best[index].1)
+            })
+    }
+
+    pub fn restart(&mut self) {
+        *self = Self::new(self.enabled);
+    }
+
+    pub fn checkpoint(&mut self) {
+        self.checkpoint = (self.splits.len(), self.path.len());
+    }
+
+    pub fn retry(&mut self) {
+        let (splits, samples) = self.checkpoint;
+        self.splits.truncate(splits);
+        self.path.truncate(samples);
+        self.delta = None;
+    }
+
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn tick(&mut self, delta_time: f64) {
+        self.time += delta_time;
+    }
+
+    pub fn sample(&mut self, scene: &str, lemonhead: &Lemonhead) {
+        let due = self
+            .path
+            .last()
+            .is_none_or(|last| self.time - last.time >= SAMPLE_SECONDS || last.scene != scene);
+        if due {
+            self.path.push(Sample {
+                time: self.time,
+                scene: scene.to_owned(),
+                x: lemonhead.x,
+                y: lemonhead.y,
+            });
+        }
+    }
+
+    pub fn split(&mut self, name: &str, now: f64) {
+        let index = self.splits.len();
+        self.delta = self.rival().and_then(|route| {
+            let (best_name, best_time) = &route.personal_best[index];
+            (best_name == name).then_some((self.time - best_time, now))
+        });
+        self.splits.push((name.to_owned(), self.time));
+    }
+
+    pub fn finish(&mut self, result: GameResult, now: f64) {
+        let name = match result {
+            GameResult::GoodEnding => "escaped",
+            GameResult::Ascended => "ascended",
+            GameResult::Dead | GameResult::Quit => return,
+        };
+        self.split(name, now);
+        let Some(route) = self.routes.iter_mut().find(|route| route.name == name) else {
+            return;
+        };
+        let improved = route
+            .personal_best
+            .last()
+            .is_none_or(|(_, best)| self.time < *best);
+        if !improved {
+            return;
+        }
+        route.personal_best.clone_from(&self.splits);
+        route.ghost = std::mem::take(&mut self.path);
+        route.save();
+    }
+
+    pub fn draw_ghost<C: Ctx>(&self, ctx: &mut C, scene: &str) {
+        if !self.enabled {
+            return;
+        }
+        let Some(Route { ghost, .. }) = self.rival() else {
+            return;
+        };
+        let next = ghost.partition_point(|sample| sample.time <= self.time);
+        let Some(previous) = next.checked_sub(1).and_then(|index| ghost.get(index)) else {
+            return;
+        };
+        if previous.scene != scene {
+            return;
+        }
+        let (x, y, state) = match ghost.get(next) {
+            Some(next) if next.scene == scene => {
+                let t = (self.time - previous.time) / (next.time - previous.time);
+                let state = if next.x < previous.x {
+                    ActorState::Left
+                } else if next.x > previous.x {
+                    ActorState::Right
+                } else {
+                    ActorState::Idle
+                };
+                (
+                    previous.x + (next.x - previous.x) * t,
+                    previous.y + (next.y - previous.y) * t,
+                    state,
+                )
+            }
+            _ => (previous.x, previous.y, ActorState::Idle),
+        };
+        let use_alt = ctx.seconds_elapsed() % 0.5 > 0.25;
+        ctx.draw_sprite_alpha(
+            Layer::Actors,
+            (x, y),
+            (1.0, 1.0),
+            &Actor::lemonhead_sprite(&state, use_alt),
+            Rgb(160, 200, 255),
+            110,
+        );
+    }
+
+    pub fn draw<C: Ctx>(&self, ctx: &mut C) {
+        if !self.enabled {
+            return;
+        }
+        let style = TextStyle {
+            spacing: Spacing::Monospace,
+            align: Align::Right,
+            ..TextStyle::small(Rgb(255, 255, 255))
+        };
+        let text = format_time(self.time);
+        let padding = 0.125;
+        let mut width = style.measure(&text) + padding * 2.0;
+        let delta = self
+            .delta
+            .filter(|(_, shown)| ctx.seconds_elapsed() - shown < DELTA_SECONDS)
+            .map(|(delta, _)| (format_delta(delta), delta));
+        if let Some((ref delta_text, _)) = delta {
+            width += style.measure(delta_text) + padding;
+        }
+        let height = style.cap_height() + padding * 2.0;
+        ctx.draw_rect_alpha(
+            Layer::Overlay,
+            Rgb(0, 0, 0),
+            160,
+            (VIEW_WIDTH - width, 0.0),
+            (width, height),
+        );
+        ctx.draw_text(
+            Layer::Overlay,
+            (VIEW_WIDTH - padding, padding),
+            &text,
+            &style,
+        );
+        if let Some((delta_text, delta)) = delta {
+            let color = if delta < 0.0 {
+                Rgb(90, 220, 90)
+            } else {
+                Rgb(230, 80, 80)
+            };
+            ctx.draw_text(
+                Layer::Overlay,
+                (VIEW_WIDTH - width + padding, padding),
+                &delta_text,
+                &TextStyle {
+                    color,
+                    align: Align::Left,
+                    ..style
+                },
+            );
+        }
+    }
+}
@@ -106,45 +106,64 @@ impl Sprite for Actor {
     }
 }
 
-pub enum Text {
-    NoWitnesses,
-    SelfDefense,
-    Space,
-    OneLeft,
-    More,
-    Ascend,
+const FONT_CHARS: &str = concat!(
+    " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_",
+    "ÆØÅ",
+    "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
+);
+
+const GLYPH_WIDTHS: &[u32] = &[
+    3, 1, 3, 5, 3, 3, 3, 1, 2, 2, 3, 3, 1, 3, 1, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 1, 1, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 3, 5, 4, 3, 3, 3, 3, 3, 3, 3, 3, 5, 3, 3, 3, 2, 3, 2, 3, 3,
+    5, 4, 3, 3, 3, 3, 3, 4, 3, 3, 5, 3, 4, 4, 3, 3, 5, 3, 3, 3, 3, 3, 3, 3, 5, 3, 3, 3, 5, 5, 4, 5,
+    3, 3, 5, 3,
+];
+
+pub struct Glyph(usize);
+
+impl Glyph {
+    pub fn new(character: char) -> Self {
+        let position = |character| FONT_CHARS.chars().position(|c| c == character);
+        let index = position(character)
+            .or_else(|| character.to_uppercase().next().and_then(position))
+            .or_else(|| position('?'))
+            .expect("font contains '?'");
+        Self(index)
+    }
+
+    pub fn width(&self) -> u32 {
+        GLYPH_WIDTHS[self.0]
+    }
 }
 
-impl Text {
-    pub fn width(&self) -> f64 {
-        match self {
-            Text::SelfDefense | Text::NoWitnesses => 4.0,
-            Text::Space | Text::OneLeft | Text::More | Text::Ascend => 2.0,
-        }
+impl Sprite for Glyph {
+    fn size(&self) -> (u32, u32) {
+        (8, 8)
+    }
+
+    fn offset(&self) -> (u32, u32) {
+        let index = self.0 as u32;
+        (index % 16 * 8, index / 16 * 8)
+    }
+
+    fn path(&self) -> &'static str {
+        "assets/font.png"
     }
 }
 
-impl Sprite for Text {
+pub struct Logo;
+
+impl Sprite for Logo {
     fn size(&self) -> (u32, u32) {
-        match self {
-            Text::SelfDefense | Text::NoWitnesses => (64, 16),
-            Text::Space | Text::OneLeft | Text::More | Text::Ascend => (32, 16),
-        }
+        (64, 24)
     }
 
     fn offset(&self) -> (u32, u32) {
-        match self {
-            Text::NoWitnesses => (0, 0),
-            Text::SelfDefense => (0, 16),
-            Text::Space => (0, 32),
-            Text::OneLeft => (32, 32),
-            Text::More => (0, 48),
-            Text::Ascend => (32, 48),
-        }
+        (160, 214)
     }
 
     fn path(&self) -> &'static str {
-        "assets/prompt.png"
+        "assets/tile.png"
     }
 }
 
@@ -218,7 +237,6 @@ pub enum Tile {
     Cloud1,
     Cloud2,
     Cloud3,
-    GameOver,
     TreeTrunk,
     LemonSkull,
     TreeLeaves,
@@ -257,11 +275,6 @@ pub enum Tile {
     Weapon,
     Coin,
     Key,
-    IntroductionText,
-    IntroductionGoalsText,
-    RememberText,
-    VoicesText,
-    Logo,
     Ascension0,
     Ascension1,
     Ascension2,
@@ -271,12 +284,8 @@ pub enum Tile {
 impl Sprite for Tile {
     fn size(&self) -> (u32, u32) {
         let (x, y) = match self {
-            Tile::Logo => (4, 4),
             Tile::CityLayer2 | Tile::LemonCar0 | Tile::LemonCar1 => (4, 2),
-            Tile::IntroductionText => (4, 1),
-            Tile::IntroductionGoalsText => (8, 1),
-            Tile::RememberText => (6, 1),
-            Tile::VoicesText | Tile::GameOver | Tile::LemonSkull => (2, 1),
+            Tile::LemonSkull => (2, 1),
             Tile::Ascension0 | Tile::Ascension1 | Tile::Ascension2 | Tile::Ascension3 => (2, 8),
             _ => (2, 2),
         };
@@ -290,7 +299,6 @@ impl Sprite for Tile {
             Tile::LemonAngel1 => (12, 10),
             Tile::Bike => (0, 12),
             Tile::LemonSkull => (8, 12),
-            Tile::GameOver => (8, 13),
             Tile::Cross => (2, 12),
             Tile::Cloud0 => (12, 0),
             Tile::Cloud1 => (12, 2),
@@ -331,11 +339,6 @@ impl Sprite for Tile {
             Tile::Weapon => (10, 6),
             Tile::Coin => (10, 4),
             Tile::Key => (4, 2),
-            Tile::IntroductionText => (0, 14),
-            Tile::IntroductionGoalsText => (0, 15),
-            Tile::RememberText => (4, 14),
-            Tile::VoicesText => (8, 15),
-            Tile::Logo => (10, 12),
             Tile::Ascension0 => (0, 0),
             Tile::Ascension1 => (2, 0),
             Tile::Ascension2 => (4, 0),
@@ -1,53 +1,39 @@
-use crate::{ctx::Ctx, scenes::Scenes};
+use std::path::Path;
 
-pub struct Tutorial {
-    pub coin: bool,
-}
-
-pub struct Outside {
-    pub key_collected: bool,
-}
-
-pub struct Entryway {
-    pub coins: [bool; 4],
-}
-
-impl Entryway {
-    pub fn all_coins_collected(&self) -> bool {
-        self.coins.iter().all(|v| *v)
-    }
-}
-
-pub struct Kitchen {
-    pub coins: [bool; 3],
-    pub weapon_collected: bool,
-}
-
-impl Kitchen {
-    pub fn all_coins_collected(&self) -> bool {
-        self.coins.iter().all(|v| *v)
-    }
-}
+use crate::{
+    achievements::Event,
+    cutscene::Cutscene,
+    dialogue::Dialogue,
+    inventory::{Inventory, Pickup, Requirement, LIVING_ROOM_COINS},
+    scenes::Scenes,
+    storage,
+};
 
+#[derive(Clone)]
 pub struct LivingRoom {
-    pub coins: [bool; 2],
-    pub dad_attack_seconds: f64,
+    pub dad_position: f64,
     pub has_escaped_dad: bool,
 }
 
+const DAD_START: f64 = 14.0;
+
 impl LivingRoom {
-    pub fn all_coins_collected(&self) -> bool {
-        self.coins.iter().all(|v| *v)
+    pub fn dad_walked(&self) -> f64 {
+        DAD_START - self.dad_position
     }
 }
 
+#[derive(Clone)]
 pub struct MurderLivingRoom {
     pub murderous_intent: bool,
     pub dad_dead: bool,
 }
 
+#[derive(Clone)]
 pub struct ChildRoom {
     pub child_stabs: u8,
+    pub greeted: bool,
+    pub playing_hide_and_seek: bool,
 }
 
 impl ChildRoom {
@@ -56,53 +42,126 @@ impl ChildRoom {
     }
 }
 
+#[derive(Clone, Copy)]
+pub enum Flag {
+    PlayingHideAndSeek,
+}
+
+#[derive(Clone, Copy)]
 pub enum EndingChosen {
     Ascended,
     Escaped,
 }
 
-pub struct State<C: Ctx> {
-    pub tutorial: Tutorial,
-    pub outside: Outside,
-    pub entryway: Entryway,
-    pub kitchen: Kitchen,
+pub struct State {
+    pub inventory: Inventory,
     pub living_room: LivingRoom,
     pub murder_living_room: MurderLivingRoom,
     pub child_room: ChildRoom,
     pub ending_chosen: Option<EndingChosen>,
-    pub scene_changed: Option<(f64, Scenes<C>)>,
-}
-
-fn no_coins_collected<const N: usize>() -> [bool; N] {
-    [false; N]
+    pub dialogue: Option<Dialogue>,
+    pub cutscene: Option<Cutscene>,
+    pub scene_changed: Option<(f64, Scenes)>,
+    pub events: Vec<Event>,
 }
 
-impl<C: Ctx> State<C> {
+impl State {
     pub fn new() -> Self {
         Self {
-            tutorial: Tutorial { coin: false },
-            outside: Outside {
-                key_collected: false,
-            },
-            entryway: Entryway {
-                coins: no_coins_collected(),
-            },
-            kitchen: Kitchen {
-                coins: no_coins_collected(),
-                weapon_collected: false,
-            },
+            inventory: Inventory::new(),
             living_room: LivingRoom {
-                coins: no_coins_collected(),
-                dad_attack_seconds: 0.0,
+                dad_position: DAD_START,
                 has_escaped_dad: false,
             },
             murder_living_room: MurderLivingRoom {
                 murderous_intent: false,
                 dad_dead: false,
             },
-            child_room: ChildRoom { child_stabs: 0 },
+            child_room: ChildRoom {
+                child_stabs: 0,
+                greeted: false,
+                playing_hide_and_seek: false,
+            },
             ending_chosen: None,
+            dialogue: None,
+            cutscene: None,
+            scene_changed: None,
+            events: Vec::new(),
+        }
+    }
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut state = Self::new();
+        for (field, value) in storage::read_pairs_from(path)? {
+            state.set_field(&field, &value)?;
+        }
+        Ok(state)
+    }
+    pub fn checkpoint(&self) -> Self {
+        Self {
+            inventory: self.inventory.clone(),
+            living_room: self.living_room.clone(),
+            murder_living_room: self.murder_living_room.clone(),
+            child_room: self.child_room.clone(),
+            ending_chosen: self.ending_chosen,
+            dialogue: None,
+            cutscene: None,
             scene_changed: None,
+            events: Vec::new(),
+        }
+    }
+    pub fn emit(&mut self, event: Event) {
+        self.events.push(event);
+    }
+    pub fn pick_up(&mut self, pickup: Pickup) {
+        self.inventory.pick_up(pickup);
+        self.emit(Event::PickedUp(pickup.kind()));
+    }
+    pub fn meets(&self, requirement: &Requirement) -> bool {
+        self.inventory.meets(requirement)
+    }
+    pub fn scripted(&self) -> bool {
+        self.cutscene.as_ref().is_some_and(Cutscene::blocking)
+    }
+    pub fn dad_chasing(&self) -> bool {
+        self.meets(&Requirement::AllOf(LIVING_ROOM_COINS)) && !self.living_room.has_escaped_dad
+    }
+    pub fn set_flag(&mut self, flag: Flag) {
+        match flag {
+            Flag::PlayingHideAndSeek => self.child_room.playing_hide_and_seek = true,
+        }
+    }
+    pub fn set_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        fn parse<T: std::str::FromStr>(field: &str, value: &str) -> Result<T, String> {
+            value
+                .parse()
+                .map_err(|_| format!("invalid value '{value}' for {field}"))
+        }
+        if let Some(pickup) = Pickup::parse(field) {
+            if parse(field, value)? {
+                self.inventory.pick_up(pickup);
+            } else {
+                self.inventory.put_back(pickup);
+            }
+            return Ok(());
+        }
+        match field {
+            "living_room.dad_position" => self.living_room.dad_position = parse(field, value)?,
+            "living_room.has_escaped_dad" => {
+                self.living_room.has_escaped_dad = parse(field, value)?
+            }
+            "murder_living_room.murderous_intent" => {
+                self.murder_living_room.murderous_intent = parse(field, value)?
+            }
+            "murder_living_room.dad_dead" => {
+                self.murder_living_room.dad_dead = parse(field, value)?
+            }
+            "child_room.child_stabs" => self.child_room.child_stabs = parse(field, value)?,
+            "child_room.greeted" => self.child_room.greeted = parse(field, value)?,
+            "child_room.playing_hide_and_seek" => {
+                self.child_room.playing_hide_and_seek = parse(field, value)?
+            }
+            _ => return Err(format!("unknown field '{field}'")),
         }
+        Ok(())
     }
 }
@@ -0,0 +1,40 @@
+use std::path::{Path, PathBuf};
+
+fn directory() -> PathBuf {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .or_else(|| std::env::var_os("APPDATA"))
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("lemonhead"))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+pub fn read_pairs(name: &str) -> Vec<(String, String)> {
+    read_pairs_from(&directory().join(name)).unwrap_or_default()
+}
+
+pub fn read_pairs_from(path: &Path) -> Result<Vec<(String, String)>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("unable to read {}: {e}", path.display()))?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_owned(), value.trim().to_owned()))
+        .collect())
+}
+
+pub fn write_pairs(name: &str, pairs: &[(&str, String)]) -> Result<(), String> {
+    let content: String = pairs
+        .iter()
+        .map(|(key, value)| format!("{key}={value}\n"))
+        .collect();
+    write_file(name, &content)
+}
+
+pub fn write_file(name: &str, content: &str) -> Result<(), String> {
+    let directory = directory();
+    std::fs::create_dir_all(&directory).map_err(|e| e.to_string())?;
+    std::fs::write(directory.join(name), content).map_err(|e| e.to_string())
+}
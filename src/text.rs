@@ -0,0 +1,102 @@
+use crate::{ctx::Rgb, sprite::Glyph};
+
+pub const GLYPH_CELL: f64 = 8.0;
+const CAP_HEIGHT: f64 = 5.0;
+const LINE_HEIGHT: f64 = 7.0;
+const MONOSPACE_ADVANCE: u32 = 6;
+
+#[derive(Clone, Copy)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Copy)]
+pub enum Spacing {
+    Monospace,
+    Proportional,
+}
+
+pub struct TextStyle {
+    pub pixel_size: f64,
+    pub color: Rgb,
+    pub align: Align,
+    pub spacing: Spacing,
+    pub wrap_width: Option<f64>,
+}
+
+impl TextStyle {
+    pub fn small(color: Rgb) -> Self {
+        Self {
+            pixel_size: 1.0 / 16.0,
+            color,
+            align: Align::Left,
+            spacing: Spacing::Proportional,
+            wrap_width: None,
+        }
+    }
+
+    pub fn large(color: Rgb) -> Self {
+        Self {
+            pixel_size: 3.0 / 32.0,
+            ..Self::small(color)
+        }
+    }
+
+    pub fn cap_height(&self) -> f64 {
+        CAP_HEIGHT * self.pixel_size
+    }
+
+    pub fn line_height(&self) -> f64 {
+        LINE_HEIGHT * self.pixel_size
+    }
+
+    pub fn advance(&self, glyph: &Glyph) -> f64 {
+        let pixels = match self.spacing {
+            Spacing::Monospace => MONOSPACE_ADVANCE,
+            Spacing::Proportional => glyph.width() + 1,
+        };
+        f64::from(pixels) * self.pixel_size
+    }
+
+    pub fn measure(&self, line: &str) -> f64 {
+        let width: f64 = line.chars().map(|c| self.advance(&Glyph::new(c))).sum();
+        (width - self.pixel_size).max(0.0)
+    }
+
+    pub fn lines(&self, text: &str) -> Vec<String> {
+        let mut lines = Vec::new();
+        for paragraph in text.split('\n') {
+            let Some(wrap_width) = self.wrap_width else {
+                lines.push(paragraph.to_owned());
+                continue;
+            };
+            let mut line = String::new();
+            for word in paragraph.split(' ') {
+                let candidate = if line.is_empty() {
+                    word.to_owned()
+                } else {
+                    format!("{line} {word}")
+                };
+                if self.measure(&candidate) > wrap_width && !line.is_empty() {
+                    lines.push(std::mem::replace(&mut line, word.to_owned()));
+                } else {
+                    line = candidate;
+                }
+            }
+            lines.push(line);
+        }
+        lines
+    }
+
+    pub fn size(&self, text: &str) -> (f64, f64) {
+        let lines = self.lines(text);
+        let width = lines
+            .iter()
+            .map(|line| self.measure(line))
+            .fold(0.0, f64::max);
+        let height = (lines.len() as f64 - 1.0) * self.line_height() + self.cap_height();
+        (width, height)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    camera::VIEW_WIDTH,
+    ctx::{Ctx, Layer, Rgb},
+};
+
+const IRIS_STRIP: f64 = 1.0 / 32.0;
+
+#[derive(Clone, Copy)]
+pub enum TransitionKind {
+    Fade(Rgb),
+    Wipe,
+    Iris,
+}
+
+pub struct Transition {
+    kind: TransitionKind,
+    started: f64,
+    seconds: f64,
+    covering: bool,
+}
+
+impl Transition {
+    pub fn cover(kind: TransitionKind, now: f64) -> Self {
+        Self {
+            kind,
+            started: now,
+            seconds: 0.5,
+            covering: true,
+        }
+    }
+
+    pub fn reveal(kind: TransitionKind, now: f64) -> Self {
+        Self {
+            covering: false,
+            ..Self::cover(kind, now)
+        }
+    }
+
+    pub fn covering(&self) -> bool {
+        self.covering
+    }
+
+    pub fn finished(&self, now: f64) -> bool {
+        now - self.started >= self.seconds
+    }
+
+    fn coverage(&self, now: f64) -> f64 {
+        let t = ((now - self.started) / self.seconds).clamp(0.0, 1.0);
+        if self.covering {
+            t
+        } else {
+            1.0 - t
+        }
+    }
+
+    pub fn draw<C: Ctx>(&self, ctx: &mut C, focus: (f64, f64)) {
+        let coverage = self.coverage(ctx.seconds_elapsed());
+        let black = Rgb(0, 0, 0);
+        match self.kind {
+            TransitionKind::Fade(color) => {
+                let alpha = (coverage * 255.0).round() as u8;
+                ctx.draw_rect_alpha(
+                    Layer::Overlay,
+                    color,
+                    alpha,
+                    (0.0, 0.0),
+                    (VIEW_WIDTH, VIEW_WIDTH),
+                );
+            }
+            TransitionKind::Wipe => {
+                let (x, width) = if self.covering {
+                    (0.0, coverage * VIEW_WIDTH)
+                } else {
+                    ((1.0 - coverage) * VIEW_WIDTH, coverage * VIEW_WIDTH)
+                };
+                ctx.draw_rect(Layer::Overlay, black, (x, 0.0), (width, VIEW_WIDTH));
+            }
+            TransitionKind::Iris => {
+                let (center_x, center_y) = ctx.camera().to_view(focus);
+                let radius = (1.0 - coverage) * VIEW_WIDTH * std::f64::consts::SQRT_2;
+                let strips = (VIEW_WIDTH / IRIS_STRIP) as u32;
+                for strip in 0..strips {
+                    let y = f64::from(strip) * IRIS_STRIP;
+                    let dy = y + IRIS_STRIP / 2.0 - center_y;
+                    if dy.abs() >= radius {
+                        ctx.draw_rect(Layer::Overlay, black, (0.0, y), (VIEW_WIDTH, IRIS_STRIP));
+                        continue;
+                    }
+                    let half_width = (radius * radius - dy * dy).sqrt();
+                    let left = (center_x - half_width).max(0.0);
+                    let right = (center_x + half_width).min(VIEW_WIDTH);
+                    ctx.draw_rect(Layer::Overlay, black, (0.0, y), (left, IRIS_STRIP));
+                    ctx.draw_rect(
+                        Layer::Overlay,
+                        black,
+                        (right, y),
+                        (VIEW_WIDTH - right, IRIS_STRIP),
+                    );
+                }
+            }
+        }
+    }
+}